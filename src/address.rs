@@ -0,0 +1,170 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bech32::{
+    Bech32m, Hrp,
+    primitives::decode::{CheckedHrpstring, CheckedHrpstringError},
+};
+use parity_scale_codec::{DecodeAll, Encode, alloc::string::String};
+use strum::IntoEnumIterator as _;
+
+use crate::{
+    ChainType, DelegationIdTag, Destination, DestinationTag, Id, OrderIdTag, PoolIdTag, PscVec,
+    TokenIdTag, VrfPublicKey,
+};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    #[error("Invalid bech32m string")]
+    InvalidFormat,
+
+    #[error("Invalid bech32m checksum")]
+    InvalidChecksum,
+
+    /// The prefix isn't used by any value of the requested type on the requested chain.
+    #[error("Wrong address prefix {actual}")]
+    WrongPrefix { actual: String },
+
+    /// The prefix is used by the requested type, but not by the decoded value, e.g. a public key
+    /// hash destination with the prefix of multisig destinations.
+    #[error("Address prefix mismatch: expected {expected}, got {actual}")]
+    PrefixMismatch {
+        expected: &'static str,
+        actual: String,
+    },
+
+    #[error("Invalid address payload")]
+    InvalidPayload,
+
+    #[error("Cannot encode the address")]
+    EncodingError,
+}
+
+/// A type that can be represented as a bech32m-encoded address.
+///
+/// The data part of the address is the encoded value; the human-readable part depends on
+/// the chain and, possibly, on the value itself.
+pub trait Addressable: Encode + DecodeAll {
+    fn address_prefix(&self, chain_type: ChainType) -> &'static str;
+
+    /// All prefixes that values of this type can have on the given chain.
+    fn address_prefixes(chain_type: ChainType) -> impl Iterator<Item = &'static str>;
+}
+
+impl Addressable for Destination {
+    fn address_prefix(&self, chain_type: ChainType) -> &'static str {
+        chain_type.destination_address_prefix(DestinationTag::from(self))
+    }
+
+    fn address_prefixes(chain_type: ChainType) -> impl Iterator<Item = &'static str> {
+        DestinationTag::iter().map(move |tag| chain_type.destination_address_prefix(tag))
+    }
+}
+
+impl Addressable for VrfPublicKey {
+    fn address_prefix(&self, chain_type: ChainType) -> &'static str {
+        chain_type.vrf_public_key_address_prefix()
+    }
+
+    fn address_prefixes(chain_type: ChainType) -> impl Iterator<Item = &'static str> {
+        core::iter::once(chain_type.vrf_public_key_address_prefix())
+    }
+}
+
+/// A tag of an id type whose values can be represented as bech32m addresses.
+pub trait AddressableIdTag {
+    fn address_prefix(chain_type: ChainType) -> &'static str;
+}
+
+impl AddressableIdTag for PoolIdTag {
+    fn address_prefix(chain_type: ChainType) -> &'static str {
+        chain_type.pool_id_address_prefix()
+    }
+}
+
+impl AddressableIdTag for DelegationIdTag {
+    fn address_prefix(chain_type: ChainType) -> &'static str {
+        chain_type.delegation_id_address_prefix()
+    }
+}
+
+impl AddressableIdTag for TokenIdTag {
+    fn address_prefix(chain_type: ChainType) -> &'static str {
+        chain_type.token_id_address_prefix()
+    }
+}
+
+impl AddressableIdTag for OrderIdTag {
+    fn address_prefix(chain_type: ChainType) -> &'static str {
+        chain_type.order_id_address_prefix()
+    }
+}
+
+impl<Tag: AddressableIdTag> Addressable for Id<Tag> {
+    fn address_prefix(&self, chain_type: ChainType) -> &'static str {
+        Tag::address_prefix(chain_type)
+    }
+
+    fn address_prefixes(chain_type: ChainType) -> impl Iterator<Item = &'static str> {
+        core::iter::once(Tag::address_prefix(chain_type))
+    }
+}
+
+/// Encode the value as a bech32m address for the given chain.
+pub fn encode_address<T: Addressable>(
+    chain_type: ChainType,
+    value: &T,
+) -> Result<String, AddressError> {
+    let hrp =
+        Hrp::parse(value.address_prefix(chain_type)).map_err(|_| AddressError::EncodingError)?;
+
+    value
+        .using_encoded(|data| bech32::encode::<Bech32m>(hrp, data))
+        .map_err(|_| AddressError::EncodingError)
+}
+
+/// Decode a bech32m address for the given chain.
+pub fn decode_address<T: Addressable>(
+    chain_type: ChainType,
+    address: &str,
+) -> Result<T, AddressError> {
+    let checked = CheckedHrpstring::new::<Bech32m>(address).map_err(|err| match err {
+        CheckedHrpstringError::Checksum(_) => AddressError::InvalidChecksum,
+        _ => AddressError::InvalidFormat,
+    })?;
+
+    let actual_prefix = checked.hrp();
+
+    if !T::address_prefixes(chain_type).any(|prefix| actual_prefix == Hrp::parse_unchecked(prefix))
+    {
+        return Err(AddressError::WrongPrefix {
+            actual: actual_prefix.to_lowercase(),
+        });
+    }
+
+    let data = checked.byte_iter().collect::<PscVec<u8>>();
+    let value = T::decode_all(&mut data.as_slice()).map_err(|_| AddressError::InvalidPayload)?;
+
+    let expected_prefix = value.address_prefix(chain_type);
+
+    if actual_prefix != Hrp::parse_unchecked(expected_prefix) {
+        return Err(AddressError::PrefixMismatch {
+            expected: expected_prefix,
+            actual: actual_prefix.to_lowercase(),
+        });
+    }
+
+    Ok(value)
+}
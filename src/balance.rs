@@ -0,0 +1,313 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::alloc::collections::BTreeMap;
+
+use crate::{
+    AccountCommand, AccountSpending, Amount, Currency, OrderAccountCommand, OutputValue,
+    SighashInputCommitment, Transaction, TxInput, TxOutput, calculate_conclude_order_values,
+    calculate_fill_order_v1_value,
+};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum BalanceError {
+    #[error("Amount overflow for currency {0:?}")]
+    Overflow(Currency),
+
+    #[error(
+        "Insufficient balance for currency {currency:?}: available {available:?}, required {required:?}"
+    )]
+    InsufficientBalance {
+        currency: Currency,
+        available: Amount,
+        required: Amount,
+    },
+
+    #[error("Input commitment count {commitment_count} doesn't match input count {input_count}")]
+    InvalidInputCommitmentCount {
+        input_count: usize,
+        commitment_count: usize,
+    },
+
+    #[error("Input commitment for input {input_index} doesn't match the input")]
+    InvalidInputCommitment { input_index: usize },
+
+    #[error("Invalid order fill in input {input_index}")]
+    InvalidOrderFill { input_index: usize },
+
+    #[error("Invalid order conclusion in input {input_index}")]
+    InvalidOrderConclusion { input_index: usize },
+
+    #[error("The value of input {input_index} can't be determined")]
+    UnsupportedInput { input_index: usize },
+}
+
+/// Per-currency sums of output values.
+///
+/// Currencies with zero balance are not stored, so two accumulators holding the same non-zero
+/// balances compare equal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueAccumulator {
+    balances: BTreeMap<Currency, Amount>,
+}
+
+impl ValueAccumulator {
+    pub fn new() -> Self {
+        Self {
+            balances: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&self, currency: Currency) -> Amount {
+        self.balances.get(&currency).copied().unwrap_or(Amount::ZERO)
+    }
+
+    /// Iterate over the non-zero balances, ordered by currency.
+    pub fn iter(&self) -> impl Iterator<Item = (Currency, Amount)> + '_ {
+        self.balances.iter().map(|(currency, amount)| (*currency, *amount))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+    }
+
+    pub fn add(&mut self, value: &OutputValue) -> Result<(), BalanceError> {
+        self.add_amount(value.currency(), value.amount())
+    }
+
+    pub fn add_amount(&mut self, currency: Currency, amount: Amount) -> Result<(), BalanceError> {
+        let new_balance =
+            self.get(currency).checked_add(amount).ok_or(BalanceError::Overflow(currency))?;
+        self.set(currency, new_balance);
+        Ok(())
+    }
+
+    pub fn sub(&mut self, value: &OutputValue) -> Result<(), BalanceError> {
+        self.sub_amount(value.currency(), value.amount())
+    }
+
+    pub fn sub_amount(&mut self, currency: Currency, amount: Amount) -> Result<(), BalanceError> {
+        let new_balance = self.balance_after_sub(currency, amount)?;
+        self.set(currency, new_balance);
+        Ok(())
+    }
+
+    /// Subtract all balances of `other` from this accumulator.
+    ///
+    /// Either all balances are subtracted or, if any of them is insufficient, none is.
+    pub fn sub_all(&mut self, other: &ValueAccumulator) -> Result<(), BalanceError> {
+        other.iter().try_for_each(|(currency, amount)| {
+            self.balance_after_sub(currency, amount).map(|_| ())
+        })?;
+
+        other
+            .iter()
+            .try_for_each(|(currency, amount)| self.sub_amount(currency, amount))
+    }
+
+    fn balance_after_sub(
+        &self,
+        currency: Currency,
+        amount: Amount,
+    ) -> Result<Amount, BalanceError> {
+        let available = self.get(currency);
+        available.checked_sub(amount).ok_or(BalanceError::InsufficientBalance {
+            currency,
+            available,
+            required: amount,
+        })
+    }
+
+    fn set(&mut self, currency: Currency, amount: Amount) {
+        if amount == Amount::ZERO {
+            self.balances.remove(&currency);
+        } else {
+            self.balances.insert(currency, amount);
+        }
+    }
+}
+
+/// Calculate the difference between the values consumed by the transaction's inputs and the
+/// values produced by its outputs, per currency.
+///
+/// The coin balance of the result is the implicit fee of the transaction; token balances are the
+/// amounts of tokens that are consumed but not transferred anywhere.
+///
+/// `input_commitments` must contain one commitment per transaction input; for UTXO inputs,
+/// it provides the spent output. An error is returned if the outputs take more than the inputs
+/// provide in any currency, e.g. if an order conclusion is used to withdraw more than the order
+/// holds. Legacy (V0) order commands are not supported, because their commitments don't carry
+/// the order balances.
+pub fn transaction_surplus(
+    tx: &Transaction,
+    input_commitments: &[SighashInputCommitment],
+) -> Result<ValueAccumulator, BalanceError> {
+    let inputs = tx.inputs();
+
+    if input_commitments.len() != inputs.len() {
+        return Err(BalanceError::InvalidInputCommitmentCount {
+            input_count: inputs.len(),
+            commitment_count: input_commitments.len(),
+        });
+    }
+
+    let mut surplus = ValueAccumulator::new();
+    let mut outputs_total = ValueAccumulator::new();
+
+    for (input_index, (input, commitment)) in inputs.iter().zip(input_commitments).enumerate() {
+        add_input_value(
+            &mut surplus,
+            &mut outputs_total,
+            input_index,
+            input,
+            commitment,
+        )?;
+    }
+
+    for output in tx.outputs() {
+        add_output_value(&mut outputs_total, output)?;
+    }
+
+    surplus.sub_all(&outputs_total)?;
+
+    Ok(surplus)
+}
+
+/// Add the value provided by the input to `acc`; if the input also consumes some value (which
+/// is the case for order fills), add it to `consumed`.
+fn add_input_value(
+    acc: &mut ValueAccumulator,
+    consumed: &mut ValueAccumulator,
+    input_index: usize,
+    input: &TxInput,
+    commitment: &SighashInputCommitment,
+) -> Result<(), BalanceError> {
+    match input {
+        TxInput::Utxo(_) => match commitment {
+            SighashInputCommitment::Utxo(utxo) => match utxo {
+                TxOutput::Transfer(value, _)
+                | TxOutput::LockThenTransfer(value, _, _)
+                | TxOutput::Htlc(value, _) => acc.add(value),
+                TxOutput::IssueNft(token_id, _, _) => {
+                    acc.add_amount(Currency::Token(*token_id), Amount::from_atoms(1))
+                }
+                TxOutput::Burn(_)
+                | TxOutput::CreateStakePool(_, _)
+                | TxOutput::ProduceBlockFromStake(_, _)
+                | TxOutput::CreateDelegationId(_, _)
+                | TxOutput::DelegateStaking(_, _)
+                | TxOutput::IssueFungibleToken(_)
+                | TxOutput::DataDeposit(_)
+                | TxOutput::CreateOrder(_) => {
+                    Err(BalanceError::InvalidInputCommitment { input_index })
+                }
+            },
+            SighashInputCommitment::ProduceBlockFromStakeUtxo {
+                utxo: _,
+                staker_balance,
+            } => acc.add_amount(Currency::Coin, *staker_balance),
+            SighashInputCommitment::None
+            | SighashInputCommitment::FillOrderAccountCommand { .. }
+            | SighashInputCommitment::ConcludeOrderAccountCommand { .. } => {
+                Err(BalanceError::InvalidInputCommitment { input_index })
+            }
+        },
+        TxInput::Account(outpoint) => match outpoint.spending {
+            AccountSpending::DelegationBalance(_, amount) => acc.add_amount(Currency::Coin, amount),
+        },
+        TxInput::AccountCommand(_, command) => match command {
+            AccountCommand::MintTokens(token_id, amount) => {
+                acc.add_amount(Currency::Token(*token_id), *amount)
+            }
+            AccountCommand::UnmintTokens(_)
+            | AccountCommand::LockTokenSupply(_)
+            | AccountCommand::FreezeToken(_, _)
+            | AccountCommand::UnfreezeToken(_)
+            | AccountCommand::ChangeTokenAuthority(_, _)
+            | AccountCommand::ChangeTokenMetadataUri(_, _) => Ok(()),
+            AccountCommand::ConcludeOrder(_) | AccountCommand::FillOrder(_, _, _) => {
+                Err(BalanceError::UnsupportedInput { input_index })
+            }
+        },
+        TxInput::OrderAccountCommand(command) => match command {
+            OrderAccountCommand::FreezeOrder(_) => Ok(()),
+            OrderAccountCommand::FillOrder(_, fill_amount) => match commitment {
+                SighashInputCommitment::FillOrderAccountCommand {
+                    initially_asked,
+                    initially_given,
+                } => {
+                    let filled_value = calculate_fill_order_v1_value(
+                        initially_asked,
+                        initially_given,
+                        *fill_amount,
+                    )
+                    .ok_or(BalanceError::InvalidOrderFill { input_index })?;
+
+                    consumed.add_amount(initially_asked.currency(), *fill_amount)?;
+                    acc.add(&filled_value)
+                }
+                SighashInputCommitment::None
+                | SighashInputCommitment::Utxo(_)
+                | SighashInputCommitment::ProduceBlockFromStakeUtxo { .. }
+                | SighashInputCommitment::ConcludeOrderAccountCommand { .. } => {
+                    Err(BalanceError::InvalidInputCommitment { input_index })
+                }
+            },
+            OrderAccountCommand::ConcludeOrder(_) => match commitment {
+                SighashInputCommitment::ConcludeOrderAccountCommand {
+                    initially_asked,
+                    initially_given,
+                    ask_balance,
+                    give_balance,
+                } => {
+                    let (filled_value, remaining_value) = calculate_conclude_order_values(
+                        initially_asked,
+                        initially_given,
+                        *ask_balance,
+                        *give_balance,
+                    )
+                    .ok_or(BalanceError::InvalidOrderConclusion { input_index })?;
+
+                    acc.add(&filled_value)?;
+                    acc.add(&remaining_value)
+                }
+                SighashInputCommitment::None
+                | SighashInputCommitment::Utxo(_)
+                | SighashInputCommitment::ProduceBlockFromStakeUtxo { .. }
+                | SighashInputCommitment::FillOrderAccountCommand { .. } => {
+                    Err(BalanceError::InvalidInputCommitment { input_index })
+                }
+            },
+        },
+    }
+}
+
+fn add_output_value(acc: &mut ValueAccumulator, output: &TxOutput) -> Result<(), BalanceError> {
+    match output {
+        TxOutput::Transfer(value, _)
+        | TxOutput::LockThenTransfer(value, _, _)
+        | TxOutput::Burn(value)
+        | TxOutput::Htlc(value, _) => acc.add(value),
+        TxOutput::CreateStakePool(_, pool_data) => acc.add_amount(Currency::Coin, pool_data.pledge),
+        TxOutput::DelegateStaking(amount, _) => acc.add_amount(Currency::Coin, *amount),
+        TxOutput::CreateOrder(order_data) => acc.add(&order_data.give),
+        // Note: the NFT itself is created by the output, so it doesn't consume anything.
+        TxOutput::IssueNft(_, _, _)
+        | TxOutput::ProduceBlockFromStake(_, _)
+        | TxOutput::CreateDelegationId(_, _)
+        | TxOutput::IssueFungibleToken(_)
+        | TxOutput::DataDeposit(_) => Ok(()),
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Amount, DestinationTag};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, strum::EnumIter)]
+pub enum ChainType {
+    Mainnet,
+    Testnet,
+    Regtest,
+    Signet,
+}
+
+impl ChainType {
+    /// The human-readable part of the bech32m address of a destination of the given kind.
+    pub const fn destination_address_prefix(
+        &self,
+        destination_tag: DestinationTag,
+    ) -> &'static str {
+        match self {
+            ChainType::Mainnet => match destination_tag {
+                DestinationTag::AnyoneCanSpend => "mxanyonecanspend",
+                DestinationTag::PublicKeyHash => "mtc",
+                DestinationTag::PublicKey => "mptc",
+                DestinationTag::ScriptHash => "mstc",
+                DestinationTag::ClassicMultisig => "mmtc",
+            },
+            ChainType::Testnet => match destination_tag {
+                DestinationTag::AnyoneCanSpend => "taanyonecanspend",
+                DestinationTag::PublicKeyHash => "tmt",
+                DestinationTag::PublicKey => "tpmt",
+                DestinationTag::ScriptHash => "tstc",
+                DestinationTag::ClassicMultisig => "tmtc",
+            },
+            ChainType::Regtest => match destination_tag {
+                DestinationTag::AnyoneCanSpend => "rxanyonecanspend",
+                DestinationTag::PublicKeyHash => "rmt",
+                DestinationTag::PublicKey => "rpmt",
+                DestinationTag::ScriptHash => "rstc",
+                DestinationTag::ClassicMultisig => "rmtc",
+            },
+            ChainType::Signet => match destination_tag {
+                DestinationTag::AnyoneCanSpend => "sxanyonecanspend",
+                DestinationTag::PublicKeyHash => "smt",
+                DestinationTag::PublicKey => "spmt",
+                DestinationTag::ScriptHash => "sstc",
+                DestinationTag::ClassicMultisig => "smtc",
+            },
+        }
+    }
+
+    /// The human-readable part of the bech32m representation of a pool id.
+    pub const fn pool_id_address_prefix(&self) -> &'static str {
+        match self {
+            ChainType::Mainnet => "mpool",
+            ChainType::Testnet => "tpool",
+            ChainType::Regtest => "rpool",
+            ChainType::Signet => "spool",
+        }
+    }
+
+    /// The human-readable part of the bech32m representation of a delegation id.
+    pub const fn delegation_id_address_prefix(&self) -> &'static str {
+        match self {
+            ChainType::Mainnet => "mdelg",
+            ChainType::Testnet => "tdelg",
+            ChainType::Regtest => "rdelg",
+            ChainType::Signet => "sdelg",
+        }
+    }
+
+    /// The human-readable part of the bech32m representation of a token id.
+    pub const fn token_id_address_prefix(&self) -> &'static str {
+        match self {
+            ChainType::Mainnet => "mmltk",
+            ChainType::Testnet => "tmltk",
+            ChainType::Regtest => "rmltk",
+            ChainType::Signet => "smltk",
+        }
+    }
+
+    /// The human-readable part of the bech32m representation of an order id.
+    pub const fn order_id_address_prefix(&self) -> &'static str {
+        match self {
+            ChainType::Mainnet => "mordr",
+            ChainType::Testnet => "tordr",
+            ChainType::Regtest => "rordr",
+            ChainType::Signet => "sordr",
+        }
+    }
+
+    /// The human-readable part of the bech32m representation of a VRF public key.
+    pub const fn vrf_public_key_address_prefix(&self) -> &'static str {
+        match self {
+            ChainType::Mainnet => "mvrfpk",
+            ChainType::Testnet => "tvrfpk",
+            ChainType::Regtest => "rvrfpk",
+            ChainType::Signet => "svrfpk",
+        }
+    }
+}
+
+/// The number of decimal places of the coin, the same on all chains.
+pub const COIN_DECIMALS: u8 = 11;
+
+/// The number of atoms in one coin.
+pub const ATOMS_PER_COIN: u128 = 10u128.pow(COIN_DECIMALS as u32);
+
+/// Per-chain parameters that are needed to format, validate and sign transactions.
+///
+/// This is a small subset of Mintlayer Core's chain config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainConfig {
+    pub chain_type: ChainType,
+    pub coin_ticker: &'static str,
+    pub coin_decimals: u8,
+    /// The BIP44 coin type, without the hardening bit.
+    pub bip44_coin_type: u32,
+    pub min_stake_pool_pledge: Amount,
+    pub token_max_ticker_len: usize,
+    pub token_max_dec_count: u8,
+    pub token_max_uri_len: usize,
+    pub token_max_name_len: usize,
+    pub token_max_description_len: usize,
+    pub token_min_hash_len: usize,
+    pub token_max_hash_len: usize,
+}
+
+impl ChainConfig {
+    /// Create the config with the default parameters of the given chain.
+    pub const fn new(chain_type: ChainType) -> Self {
+        let (coin_ticker, bip44_coin_type) = match chain_type {
+            ChainType::Mainnet => ("ML", 19788),
+            ChainType::Testnet => ("TML", 1),
+            ChainType::Regtest => ("RML", 1),
+            ChainType::Signet => ("SML", 1),
+        };
+
+        Self {
+            chain_type,
+            coin_ticker,
+            coin_decimals: COIN_DECIMALS,
+            bip44_coin_type,
+            min_stake_pool_pledge: Amount::from_atoms(40_000 * ATOMS_PER_COIN),
+            token_max_ticker_len: 12,
+            token_max_dec_count: 18,
+            token_max_uri_len: 1024,
+            token_max_name_len: 10,
+            token_max_description_len: 100,
+            token_min_hash_len: 4,
+            token_max_hash_len: 32,
+        }
+    }
+}
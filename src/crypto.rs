@@ -15,6 +15,8 @@
 
 use parity_scale_codec::{Decode, Encode};
 
+use crate::hash_encoded;
+
 pub const PUBLIC_KEY_HASH_SIZE: usize = 20;
 pub const SECP256K1_PUBLIC_KEY_SIZE: usize = 33;
 pub const SCHNORRKEL_PUBLIC_KEY_SIZE: usize = 32;
@@ -26,6 +28,21 @@ fixed_hash::construct_fixed_hash! {
     pub struct PublicKeyHash(PUBLIC_KEY_HASH_SIZE);
 }
 
+impl PublicKeyHash {
+    /// Calculate the hash of the public key, which is the first `PUBLIC_KEY_HASH_SIZE` bytes
+    /// of the default hash of its encoded form.
+    pub fn from_public_key(public_key: &PublicKey) -> Self {
+        let hash = hash_encoded(public_key);
+        Self::from_slice(&hash.as_bytes()[..PUBLIC_KEY_HASH_SIZE])
+    }
+}
+
+impl From<&PublicKey> for PublicKeyHash {
+    fn from(public_key: &PublicKey) -> Self {
+        Self::from_public_key(public_key)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
 pub struct Secp256k1PublicKey(pub [u8; SECP256K1_PUBLIC_KEY_SIZE]);
 
@@ -0,0 +1,60 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use blake2::{Digest as _, digest::consts::U32};
+use parity_scale_codec::Encode;
+
+use crate::H256;
+
+/// The hash algorithm that Mintlayer Core uses to calculate ids, i.e. Blake2b with 32-byte output.
+pub type DefaultHashAlgo = blake2::Blake2b<U32>;
+
+/// A streaming hasher based on `DefaultHashAlgo`.
+///
+/// It implements `parity_scale_codec::Output`, so values can be encoded straight into it.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultHashAlgoStream(DefaultHashAlgo);
+
+impl DefaultHashAlgoStream {
+    pub fn new() -> Self {
+        Self(DefaultHashAlgo::new())
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    pub fn finalize(self) -> H256 {
+        H256(self.0.finalize().into())
+    }
+}
+
+impl parity_scale_codec::Output for DefaultHashAlgoStream {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+/// Hash the encoded form of the value.
+pub fn hash_encoded<T: Encode + ?Sized>(value: &T) -> H256 {
+    let mut hasher = DefaultHashAlgoStream::new();
+    hash_encoded_to(value, &mut hasher);
+    hasher.finalize()
+}
+
+/// Feed the encoded form of the value into the hasher.
+pub fn hash_encoded_to<T: Encode + ?Sized>(value: &T, hasher: &mut DefaultHashAlgoStream) {
+    value.encode_to(hasher);
+}
@@ -16,12 +16,24 @@
 #![no_std]
 
 mod accounts;
+mod address;
+mod balance;
+mod chain;
 mod crypto;
 mod destination;
+mod hash;
 mod id;
+mod math;
 mod misc;
+mod orders;
+mod sighash;
 mod sighash_input_commitment;
+mod sighash_type;
+mod signed_transaction;
+mod staking;
+mod timelock;
 mod tokens;
+mod transaction;
 mod tx_input;
 mod tx_output;
 mod utxo_outpoint;
@@ -30,12 +42,23 @@ mod utxo_outpoint;
 mod tests;
 
 pub use accounts::*;
+pub use address::*;
+pub use balance::*;
+pub use chain::*;
 pub use crypto::*;
 pub use destination::*;
+pub use hash::*;
 pub use id::*;
 pub use misc::*;
+pub use orders::*;
+pub use sighash::*;
 pub use sighash_input_commitment::*;
+pub use sighash_type::*;
+pub use signed_transaction::*;
+pub use staking::*;
+pub use timelock::*;
 pub use tokens::*;
+pub use transaction::*;
 pub use tx_input::*;
 pub use tx_output::*;
 pub use utxo_outpoint::*;
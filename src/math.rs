@@ -0,0 +1,72 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Calculate `a * b / divisor`, rounding down, with a 256-bit intermediate product, the same way
+/// Core does it using `U256`.
+///
+/// `None` is returned if the divisor is zero or the result doesn't fit into `u128`.
+pub(crate) fn mul_div(a: u128, b: u128, divisor: u128) -> Option<u128> {
+    if divisor == 0 {
+        return None;
+    }
+
+    let (hi, lo) = widening_mul(a, b);
+
+    // The quotient fits into u128 iff the high half of the dividend is less than the divisor.
+    if hi >= divisor {
+        return None;
+    }
+
+    // Binary long division; `rem` is always less than `divisor`.
+    let mut rem = hi;
+    let mut quot = 0u128;
+
+    for bit in (0..128).rev() {
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((lo >> bit) & 1);
+        quot <<= 1;
+
+        // If the shift has carried out a bit, the actual remainder exceeds u128::MAX and
+        // therefore the divisor; the wrapping subtraction still produces the right result,
+        // because the actual remainder is less than twice the divisor.
+        if carry != 0 || rem >= divisor {
+            rem = rem.wrapping_sub(divisor);
+            quot |= 1;
+        }
+    }
+
+    Some(quot)
+}
+
+/// Calculate the full 256-bit product of two `u128` values, returned as (high, low) halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const LOW_MASK: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & LOW_MASK);
+    let (b_hi, b_lo) = (b >> 64, b & LOW_MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    // Each term is less than 2^64, so the sum can't overflow.
+    let mid = (lo_lo >> 64) + (lo_hi & LOW_MASK) + (hi_lo & LOW_MASK);
+
+    let lo = (lo_lo & LOW_MASK) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
@@ -20,8 +20,86 @@ use crate::TokenId;
 pub type PscVec<T> = parity_scale_codec::alloc::vec::Vec<T>;
 
 /// The number of parts per thousand. The valid values are in [0, 1000].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
-pub struct PerThousand(pub u16);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode)]
+pub struct PerThousand(u16);
+
+impl PerThousand {
+    pub const MAX_VALUE: u16 = 1000;
+
+    /// The maximum length of the string produced by `into_percentage_str`, e.g. "12.5%".
+    pub const PERCENTAGE_STR_MAX_LEN: usize = 5;
+
+    pub const fn new(value: u16) -> Option<Self> {
+        if value <= Self::MAX_VALUE {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.0
+    }
+
+    /// Calculate the corresponding fraction of the amount, rounding down.
+    pub fn scale(&self, amount: Amount) -> Option<Amount> {
+        // Split the amount to avoid the overflow of the intermediate product.
+        let per_thousand = AmountUIntType::from(self.0);
+        let atoms = amount.into_atoms();
+        let whole_thousands = (atoms / 1000).checked_mul(per_thousand)?;
+        let remainder = (atoms % 1000).checked_mul(per_thousand)? / 1000;
+
+        whole_thousands.checked_add(remainder).map(Amount::from_atoms)
+    }
+
+    /// Format the value as a percentage, e.g. 125 becomes "12.5%".
+    ///
+    /// The string is written into `buf`; `None` is returned if it doesn't fit, which can't happen
+    /// if the buffer is at least `PERCENTAGE_STR_MAX_LEN` bytes long.
+    pub fn into_percentage_str(self, buf: &mut [u8]) -> Option<&str> {
+        let number_len = format_fixed_point(self.0.into(), PERCENT_DECIMALS, buf)?.len();
+        *buf.get_mut(number_len)? = b'%';
+
+        core::str::from_utf8(buf.get(..=number_len)?).ok()
+    }
+
+    /// Parse a percentage with at most one decimal, e.g. "12.5%" becomes 125.
+    ///
+    /// The percent sign is mandatory; the number must follow the same rules as in
+    /// `Amount::from_fixedpoint_str`.
+    pub fn from_percentage_str(s: &str) -> Option<Self> {
+        let number = s.strip_suffix('%')?;
+        let value = parse_fixed_point(number, PERCENT_DECIMALS)?;
+
+        Self::new(u16::try_from(value).ok()?)
+    }
+}
+
+impl TryFrom<u16> for PerThousand {
+    type Error = PerThousandError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(PerThousandError::OutOfRange(value))
+    }
+}
+
+impl Decode for PerThousand {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        Self::new(u16::decode(input)?)
+            .ok_or_else(|| parity_scale_codec::Error::from("PerThousand value out of range"))
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PerThousandError {
+    #[error("Per thousand value {0} is out of range")]
+    OutOfRange(u16),
+}
+
+// A per-thousand value is a percentage with one decimal.
+const PERCENT_DECIMALS: u8 = 1;
 
 pub type AmountUIntType = u128;
 
@@ -32,6 +110,9 @@ pub struct Amount {
 }
 
 impl Amount {
+    pub const ZERO: Self = Amount::from_atoms(0);
+    pub const MAX: Self = Amount::from_atoms(AmountUIntType::MAX);
+
     pub const fn from_atoms(v: AmountUIntType) -> Self {
         Amount { atoms: v }
     }
@@ -39,6 +120,198 @@ impl Amount {
     pub const fn into_atoms(&self) -> AmountUIntType {
         self.atoms
     }
+
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.atoms.checked_add(other.atoms) {
+            Some(atoms) => Some(Amount::from_atoms(atoms)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.atoms.checked_sub(other.atoms) {
+            Some(atoms) => Some(Amount::from_atoms(atoms)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_mul(self, other: AmountUIntType) -> Option<Self> {
+        match self.atoms.checked_mul(other) {
+            Some(atoms) => Some(Amount::from_atoms(atoms)),
+            None => None,
+        }
+    }
+
+    /// Integer division, rounding down; `None` if `other` is zero.
+    pub const fn checked_div(self, other: AmountUIntType) -> Option<Self> {
+        match self.atoms.checked_div(other) {
+            Some(atoms) => Some(Amount::from_atoms(atoms)),
+            None => None,
+        }
+    }
+
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Amount::from_atoms(self.atoms.saturating_add(other.atoms))
+    }
+
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Amount::from_atoms(self.atoms.saturating_sub(other.atoms))
+    }
+
+    pub const fn saturating_mul(self, other: AmountUIntType) -> Self {
+        Amount::from_atoms(self.atoms.saturating_mul(other))
+    }
+
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        Amount::from_atoms(self.atoms.wrapping_add(other.atoms))
+    }
+
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Amount::from_atoms(self.atoms.wrapping_sub(other.atoms))
+    }
+
+    pub const fn wrapping_mul(self, other: AmountUIntType) -> Self {
+        Amount::from_atoms(self.atoms.wrapping_mul(other))
+    }
+
+    /// Sum optional amounts, e.g. the results of other checked operations; `None` if any of
+    /// the items is `None` or if the sum overflows.
+    pub fn checked_sum(iter: impl IntoIterator<Item = Option<Amount>>) -> Option<Amount> {
+        iter.into_iter().try_fold(Amount::ZERO, |sum, amount| sum.checked_add(amount?))
+    }
+
+    /// The maximum length of the string produced by `into_fixedpoint_str` for the given number
+    /// of decimals.
+    pub const fn fixedpoint_str_max_len(decimals: u8) -> usize {
+        fixed_point_str_max_len(decimals)
+    }
+
+    /// Format the amount as a decimal number with the given number of decimals, e.g. 1250 atoms
+    /// with 2 decimals become "12.5".
+    ///
+    /// Trailing zeros of the fractional part are omitted, as well as the decimal point if there is
+    /// no fractional part. The string is written into `buf`; `None` is returned if it doesn't fit,
+    /// which can't happen if the buffer is at least `fixedpoint_str_max_len(decimals)` bytes long.
+    pub fn into_fixedpoint_str(self, decimals: u8, buf: &mut [u8]) -> Option<&str> {
+        format_fixed_point(self.atoms, decimals, buf)
+    }
+
+    /// Parse a decimal number with the given number of decimals, e.g. "12.5" with 2 decimals
+    /// becomes 1250 atoms.
+    ///
+    /// Only ASCII digits and at most one decimal point are accepted, with at least one digit on
+    /// each side of the point. `None` is returned if the string is malformed, if it has more
+    /// fractional digits than the given number of decimals (even if they are zeros) or if
+    /// the amount doesn't fit into `Amount`.
+    pub fn from_fixedpoint_str(s: &str, decimals: u8) -> Option<Self> {
+        parse_fixed_point(s, decimals).map(Amount::from_atoms)
+    }
+}
+
+/// Summing amounts produces `None` on overflow.
+impl core::iter::Sum<Amount> for Option<Amount> {
+    fn sum<I: Iterator<Item = Amount>>(mut iter: I) -> Self {
+        iter.try_fold(Amount::ZERO, Amount::checked_add)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Amount> for Option<Amount> {
+    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+// The number of decimal digits in `u128::MAX`.
+const U128_MAX_DIGITS: usize = 39;
+
+pub(crate) const fn fixed_point_str_max_len(decimals: u8) -> usize {
+    let decimals = decimals as usize;
+    // The integer part is at least "0"; one more byte is for the decimal point.
+    if decimals < U128_MAX_DIGITS {
+        U128_MAX_DIGITS + 1
+    } else {
+        decimals + 2
+    }
+}
+
+/// Write `value / 10^decimals` into `buf` as a decimal number without trailing zeros in the
+/// fractional part.
+pub(crate) fn format_fixed_point(value: u128, decimals: u8, buf: &mut [u8]) -> Option<&str> {
+    // The digits of the value, least significant first.
+    let mut digits = [0u8; U128_MAX_DIGITS];
+    let mut digit_count = 0;
+    let mut rest = value;
+    while rest != 0 {
+        digits[digit_count] = (rest % 10) as u8;
+        digit_count += 1;
+        rest /= 10;
+    }
+
+    // The digit at position `i` (counting from the least significant one) is zero if it's
+    // beyond the digits of the value.
+    let digit_at = |i: usize| digits.get(i).copied().unwrap_or(0);
+
+    let decimals = decimals as usize;
+    let int_digit_count = digit_count.saturating_sub(decimals).max(1);
+    let frac_trailing_zeros = (0..decimals).take_while(|i| digit_at(*i) == 0).count();
+    let frac_digit_count = decimals - frac_trailing_zeros;
+
+    let len = if frac_digit_count == 0 {
+        int_digit_count
+    } else {
+        int_digit_count + 1 + frac_digit_count
+    };
+    let out = buf.get_mut(..len)?;
+
+    let mut out_iter = out.iter_mut();
+
+    for i in (decimals..decimals + int_digit_count).rev() {
+        *out_iter.next()? = b'0' + digit_at(i);
+    }
+
+    if frac_digit_count != 0 {
+        *out_iter.next()? = b'.';
+
+        for i in (frac_trailing_zeros..decimals).rev() {
+            *out_iter.next()? = b'0' + digit_at(i);
+        }
+    }
+
+    core::str::from_utf8(out).ok()
+}
+
+/// Parse a decimal number and return it multiplied by `10^decimals`.
+pub(crate) fn parse_fixed_point(s: &str, decimals: u8) -> Option<u128> {
+    let (int_part, frac_part) = match s.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (s, None),
+    };
+
+    let is_valid_part = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+    if !is_valid_part(int_part) || !frac_part.is_none_or(is_valid_part) {
+        return None;
+    }
+
+    let push_digit =
+        |value: u128, digit: u8| value.checked_mul(10)?.checked_add((digit - b'0').into());
+
+    let mut value = int_part.bytes().try_fold(0u128, push_digit)?;
+
+    let frac_part = frac_part.unwrap_or("").as_bytes();
+    let decimals = decimals as usize;
+
+    if frac_part.len() > decimals {
+        return None;
+    }
+
+    value = frac_part.iter().copied().try_fold(value, push_digit)?;
+
+    for _ in frac_part.len()..decimals {
+        value = value.checked_mul(10)?;
+    }
+
+    Some(value)
 }
 
 /// This represents an amount of an asset, which can be either coins or tokens.
@@ -56,6 +329,37 @@ pub enum OutputValue {
     TokenV1(TokenId, Amount),
 }
 
+impl OutputValue {
+    pub fn currency(&self) -> Currency {
+        match self {
+            OutputValue::Coin(_) => Currency::Coin,
+            OutputValue::TokenV1(token_id, _) => Currency::Token(*token_id),
+        }
+    }
+
+    pub fn amount(&self) -> Amount {
+        match self {
+            OutputValue::Coin(amount) | OutputValue::TokenV1(_, amount) => *amount,
+        }
+    }
+}
+
+/// The kind of asset that an `OutputValue` holds, i.e. coins or a particular token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Currency {
+    Coin,
+    Token(TokenId),
+}
+
+impl Currency {
+    pub fn into_output_value(self, amount: Amount) -> OutputValue {
+        match self {
+            Currency::Coin => OutputValue::Coin(amount),
+            Currency::Token(token_id) => OutputValue::TokenV1(token_id, amount),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, strum::EnumDiscriminants)]
 #[strum_discriminants(name(OutputTimeLockTag), derive(strum::EnumIter))]
 pub enum OutputTimeLock {
@@ -77,15 +381,162 @@ pub type BlockHeightUIntType = u64;
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Encode, Decode)]
 pub struct BlockHeight(#[codec(compact)] pub BlockHeightUIntType);
 
+impl BlockHeight {
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// The height of the block following this one; `None` on overflow.
+    pub const fn next_height(&self) -> Option<Self> {
+        match self.0.checked_add(1) {
+            Some(height) => Some(Self(height)),
+            None => None,
+        }
+    }
+}
+
+impl core::ops::Add<BlocksCount> for BlockHeight {
+    type Output = Option<BlockHeight>;
+
+    fn add(self, count: BlocksCount) -> Self::Output {
+        self.0.checked_add(count.0).map(BlockHeight)
+    }
+}
+
+impl core::ops::Sub<BlocksCount> for BlockHeight {
+    type Output = Option<BlockHeight>;
+
+    fn sub(self, count: BlocksCount) -> Self::Output {
+        self.0.checked_sub(count.0).map(BlockHeight)
+    }
+}
+
+/// The number of blocks between two heights; `None` if the second height is bigger.
+impl core::ops::Sub<BlockHeight> for BlockHeight {
+    type Output = Option<BlocksCount>;
+
+    fn sub(self, other: BlockHeight) -> Self::Output {
+        self.0.checked_sub(other.0).map(BlocksCount)
+    }
+}
+
+impl core::fmt::Display for BlockHeight {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The number of seconds since the Unix epoch.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
 pub struct BlockTimestamp(pub SecondsCount);
 
+impl BlockTimestamp {
+    pub const fn zero() -> Self {
+        Self(SecondsCount::zero())
+    }
+
+    pub const fn from_int_seconds(seconds: SecondsCountUIntType) -> Self {
+        Self(SecondsCount(seconds))
+    }
+
+    pub const fn as_int_seconds(&self) -> SecondsCountUIntType {
+        self.0.0
+    }
+}
+
+impl core::ops::Add<SecondsCount> for BlockTimestamp {
+    type Output = Option<BlockTimestamp>;
+
+    fn add(self, seconds: SecondsCount) -> Self::Output {
+        (self.0 + seconds).map(BlockTimestamp)
+    }
+}
+
+impl core::ops::Sub<SecondsCount> for BlockTimestamp {
+    type Output = Option<BlockTimestamp>;
+
+    fn sub(self, seconds: SecondsCount) -> Self::Output {
+        (self.0 - seconds).map(BlockTimestamp)
+    }
+}
+
+/// The number of seconds between two timestamps; `None` if the second timestamp is bigger.
+impl core::ops::Sub<BlockTimestamp> for BlockTimestamp {
+    type Output = Option<SecondsCount>;
+
+    fn sub(self, other: BlockTimestamp) -> Self::Output {
+        self.0 - other.0
+    }
+}
+
+impl core::fmt::Display for BlockTimestamp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 pub type BlocksCountUIntType = u64;
 
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Encode, Decode)]
 pub struct BlocksCount(#[codec(compact)] pub BlocksCountUIntType);
 
+impl BlocksCount {
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+}
+
+impl core::ops::Add for BlocksCount {
+    type Output = Option<BlocksCount>;
+
+    fn add(self, other: BlocksCount) -> Self::Output {
+        self.0.checked_add(other.0).map(BlocksCount)
+    }
+}
+
+impl core::ops::Sub for BlocksCount {
+    type Output = Option<BlocksCount>;
+
+    fn sub(self, other: BlocksCount) -> Self::Output {
+        self.0.checked_sub(other.0).map(BlocksCount)
+    }
+}
+
+impl core::fmt::Display for BlocksCount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 pub type SecondsCountUIntType = u64;
 
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Encode, Decode)]
 pub struct SecondsCount(#[codec(compact)] pub SecondsCountUIntType);
+
+impl SecondsCount {
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+}
+
+impl core::ops::Add for SecondsCount {
+    type Output = Option<SecondsCount>;
+
+    fn add(self, other: SecondsCount) -> Self::Output {
+        self.0.checked_add(other.0).map(SecondsCount)
+    }
+}
+
+impl core::ops::Sub for SecondsCount {
+    type Output = Option<SecondsCount>;
+
+    fn sub(self, other: SecondsCount) -> Self::Output {
+        self.0.checked_sub(other.0).map(SecondsCount)
+    }
+}
+
+impl core::fmt::Display for SecondsCount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    Amount, AmountUIntType, Currency, OrderData, OutputValue, format_fixed_point, math::mul_div,
+};
+
+/// Calculate the amount of the "give" currency that the taker receives when filling an order V1
+/// with the specified amount of the "ask" currency.
+///
+/// In orders V1, the price is always determined by the initial ask and give amounts of
+/// the order: the result is `initially_given * fill_amount / initially_asked`, rounded down,
+/// calculated without intermediate overflow.
+///
+/// `None` is returned if `initially_asked` is zero or the result doesn't fit into `Amount`
+/// (which is only possible if the fill amount exceeds the initially asked amount).
+pub fn calculate_fill_order_v1(
+    initially_asked: Amount,
+    initially_given: Amount,
+    fill_amount_in_ask_currency: Amount,
+) -> Option<Amount> {
+    mul_div(
+        initially_given.into_atoms(),
+        fill_amount_in_ask_currency.into_atoms(),
+        initially_asked.into_atoms(),
+    )
+    .map(Amount::from_atoms)
+}
+
+/// Same as `calculate_fill_order_v1`, but operates on the initial values of the order, as
+/// carried by `SighashInputCommitment::FillOrderAccountCommand`, and returns the filled value
+/// in the "give" currency.
+pub fn calculate_fill_order_v1_value(
+    initially_asked: &OutputValue,
+    initially_given: &OutputValue,
+    fill_amount_in_ask_currency: Amount,
+) -> Option<OutputValue> {
+    let filled_amount = calculate_fill_order_v1(
+        initially_asked.amount(),
+        initially_given.amount(),
+        fill_amount_in_ask_currency,
+    )?;
+
+    Some(initially_given.currency().into_output_value(filled_amount))
+}
+
+/// Calculate the amount of the "give" currency that the taker receives when filling a legacy
+/// order (orders V0) with the specified amount of the "ask" currency.
+///
+/// Unlike orders V1, the price of a V0 order is determined by its current balances:
+/// the result is `give_balance * fill_amount / ask_balance`, rounded down, calculated without
+/// intermediate overflow.
+///
+/// `None` is returned if `ask_balance` is zero or if the fill amount exceeds it, i.e. if
+/// the order would be overbid.
+pub fn calculate_fill_order_v0(
+    ask_balance: Amount,
+    give_balance: Amount,
+    fill_amount_in_ask_currency: Amount,
+) -> Option<Amount> {
+    if fill_amount_in_ask_currency > ask_balance {
+        return None;
+    }
+
+    mul_div(
+        give_balance.into_atoms(),
+        fill_amount_in_ask_currency.into_atoms(),
+        ask_balance.into_atoms(),
+    )
+    .map(Amount::from_atoms)
+}
+
+/// The amounts that the owner of an order receives when concluding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcludeOrderPayout {
+    /// The amount of the "ask" currency that the order has received from fills.
+    pub filled_ask_amount: Amount,
+    /// The remaining amount of the "give" currency.
+    pub remaining_give_amount: Amount,
+}
+
+/// Calculate what the owner of an order receives when concluding it, which is the same
+/// for orders V0 and V1: everything that was paid to the order so far, i.e. the initially asked
+/// amount minus the current ask balance, plus the current give balance.
+///
+/// `None` is returned if the ask balance exceeds the initially asked amount.
+pub fn calculate_conclude_order_payout(
+    initially_asked: Amount,
+    ask_balance: Amount,
+    give_balance: Amount,
+) -> Option<ConcludeOrderPayout> {
+    Some(ConcludeOrderPayout {
+        filled_ask_amount: initially_asked.checked_sub(ask_balance)?,
+        remaining_give_amount: give_balance,
+    })
+}
+
+/// Same as `calculate_conclude_order_payout`, but operates on the data carried by
+/// `SighashInputCommitment::ConcludeOrderAccountCommand` and returns the values released by
+/// the conclusion: the filled amount in the "ask" currency and the remaining amount in the "give"
+/// currency.
+pub fn calculate_conclude_order_values(
+    initially_asked: &OutputValue,
+    initially_given: &OutputValue,
+    ask_balance: Amount,
+    give_balance: Amount,
+) -> Option<(OutputValue, OutputValue)> {
+    let payout =
+        calculate_conclude_order_payout(initially_asked.amount(), ask_balance, give_balance)?;
+
+    Some((
+        initially_asked.currency().into_output_value(payout.filled_ask_amount),
+        initially_given.currency().into_output_value(payout.remaining_give_amount),
+    ))
+}
+
+/// The largest fill that an order V1 currently accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxOrderFill {
+    /// The amount of the "ask" currency to pay, which is the whole ask balance.
+    pub fill_amount: Amount,
+    /// The amount of the "give" currency that the taker receives.
+    pub filled_amount: Amount,
+    /// The amount of the "give" currency left in the order afterwards, due to rounding down
+    /// of the fills; it's returned to the owner when the order is concluded.
+    pub remaining_give_amount: Amount,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum OrderDataError {
+    #[error("The order asks for and gives the same currency")]
+    SameCurrencies,
+
+    #[error("The order's ask amount is zero")]
+    ZeroAskAmount,
+
+    #[error("The order's give amount is zero")]
+    ZeroGiveAmount,
+}
+
+/// An exact price, expressed as a fraction in lowest terms with a non-zero denominator.
+///
+/// The numerator and denominator are amounts in atoms of their respective currencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderPrice {
+    numerator: AmountUIntType,
+    denominator: AmountUIntType,
+}
+
+impl OrderPrice {
+    /// Create a price equal to `numerator / denominator`; `None` if the denominator is zero.
+    pub fn new(numerator: Amount, denominator: Amount) -> Option<Self> {
+        let numerator = numerator.into_atoms();
+        let denominator = denominator.into_atoms();
+
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+
+        Some(Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    pub fn numerator(&self) -> AmountUIntType {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> AmountUIntType {
+        self.denominator
+    }
+
+    /// Format the price of one whole unit of the denominator's currency in units of
+    /// the numerator's currency, given the numbers of decimals of both currencies, e.g.
+    /// 1 coin (with 11 decimals) per 4 tokens (with 2 decimals) becomes "0.25".
+    ///
+    /// The result is rounded down to the numerator's number of decimals. The string is
+    /// written into `buf`, see `Amount::into_fixedpoint_str` for the requirements; `None` is
+    /// also returned if the price of one whole unit doesn't fit into `Amount`.
+    pub fn into_fixedpoint_str(
+        self,
+        numerator_decimals: u8,
+        denominator_decimals: u8,
+        buf: &mut [u8],
+    ) -> Option<&str> {
+        let denominator_unit = 10u128.checked_pow(denominator_decimals.into())?;
+        let unit_price = mul_div(self.numerator, denominator_unit, self.denominator)?;
+
+        format_fixed_point(unit_price, numerator_decimals, buf)
+    }
+}
+
+impl OrderData {
+    /// The currencies that the order asks for and gives, in this order.
+    pub fn currency_pair(&self) -> (Currency, Currency) {
+        (self.ask.currency(), self.give.currency())
+    }
+
+    /// Check that the order exchanges two different currencies and both amounts are non-zero.
+    pub fn validate(&self) -> Result<(), OrderDataError> {
+        let (ask_currency, give_currency) = self.currency_pair();
+
+        if ask_currency == give_currency {
+            return Err(OrderDataError::SameCurrencies);
+        }
+        if self.ask.amount() == Amount::ZERO {
+            return Err(OrderDataError::ZeroAskAmount);
+        }
+        if self.give.amount() == Amount::ZERO {
+            return Err(OrderDataError::ZeroGiveAmount);
+        }
+
+        Ok(())
+    }
+
+    /// The amount of the "give" currency per unit of the "ask" currency, i.e. what a taker
+    /// receives per atom paid; `None` if the ask amount is zero.
+    pub fn give_per_ask(&self) -> Option<OrderPrice> {
+        OrderPrice::new(self.give.amount(), self.ask.amount())
+    }
+
+    /// The amount of the "ask" currency per unit of the "give" currency, i.e. what a taker
+    /// pays per atom received; `None` if the give amount is zero.
+    pub fn ask_per_give(&self) -> Option<OrderPrice> {
+        OrderPrice::new(self.ask.amount(), self.give.amount())
+    }
+
+    /// The amount of the "ask" currency that has been paid to the order so far, given its
+    /// current ask balance; `None` if the ask balance exceeds the initially asked amount.
+    pub fn filled_ask_amount(&self, ask_balance: Amount) -> Option<Amount> {
+        self.ask.amount().checked_sub(ask_balance)
+    }
+
+    /// The amount of the "give" currency that has been paid out by the order so far, given its
+    /// current give balance; `None` if the give balance exceeds the initially given amount.
+    pub fn filled_give_amount(&self, give_balance: Amount) -> Option<Amount> {
+        self.give.amount().checked_sub(give_balance)
+    }
+
+    /// Calculate the largest fill that the order, as an order V1, accepts given its current
+    /// balances: the whole ask balance, exchanged at the initial price of the order.
+    ///
+    /// `None` is returned if the ask amount of the order is zero, if the ask balance exceeds it
+    /// or if the filled amount exceeds the give balance, i.e. if the balances can't belong
+    /// to this order.
+    pub fn max_fill_v1(&self, ask_balance: Amount, give_balance: Amount) -> Option<MaxOrderFill> {
+        if ask_balance > self.ask.amount() {
+            return None;
+        }
+
+        let filled_amount =
+            calculate_fill_order_v1(self.ask.amount(), self.give.amount(), ask_balance)?;
+
+        Some(MaxOrderFill {
+            fill_amount: ask_balance,
+            filled_amount,
+            remaining_give_amount: give_balance.checked_sub(filled_amount)?,
+        })
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
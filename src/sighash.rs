@@ -0,0 +1,294 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::Compact;
+
+use crate::{
+    DefaultHashAlgoStream, H256, InputsMode, OutputsMode, SigHashType, SighashInputCommitment,
+    TX_V1_VERSION_BYTE, Transaction, TxInput, TxOutput, hash_encoded_to,
+};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum SighashError {
+    #[error("Invalid input index {input_index}, the input count is {input_count}")]
+    InvalidInputIndex {
+        input_index: usize,
+        input_count: usize,
+    },
+
+    #[error("Input commitment count {commitment_count} doesn't match input count {input_count}")]
+    InvalidInputCommitmentCount {
+        input_count: usize,
+        commitment_count: usize,
+    },
+
+    #[error(
+        "No output corresponding to input {input_index} in SINGLE mode, the output count is {output_count}"
+    )]
+    NoOutputForSingleMode {
+        input_index: usize,
+        output_count: usize,
+    },
+
+    #[error("Unexpected input in the sighash stream")]
+    UnexpectedInput,
+
+    #[error("Unexpected input commitment in the sighash stream")]
+    UnexpectedInputCommitment,
+
+    #[error("Unexpected output in the sighash stream")]
+    UnexpectedOutput,
+
+    #[error("The sighash stream is incomplete")]
+    IncompleteStream,
+}
+
+/// Calculate the hash that has to be signed in order to authorize the spending of the input
+/// with the specified index.
+///
+/// `input_commitments` must contain one commitment per transaction input.
+///
+/// The hash is calculated over the concatenation of the following encoded values:
+/// 1. the sighash type byte;
+/// 2. the transaction version byte and flags (the latter as a non-compact `u128`);
+/// 3. in `CommitWhoPays` mode - the input count (as `u32`), all inputs, the commitment count
+///    (as `u32`) and all commitments; in `AnyoneCanPay` mode - only the input being signed and
+///    its commitment;
+/// 4. in `All` mode - the vector of all outputs; in `Single` mode - the output with the same
+///    index as the input being signed; nothing in `None` mode;
+/// 5. `u32::MAX`, which is a placeholder for the code separator position.
+pub fn signature_hash(
+    tx: &Transaction,
+    input_commitments: &[SighashInputCommitment],
+    input_index: usize,
+    sighash_type: SigHashType,
+) -> Result<H256, SighashError> {
+    let inputs = tx.inputs();
+    let outputs = tx.outputs();
+
+    let target_input = inputs.get(input_index).ok_or(SighashError::InvalidInputIndex {
+        input_index,
+        input_count: inputs.len(),
+    })?;
+
+    if input_commitments.len() != inputs.len() {
+        return Err(SighashError::InvalidInputCommitmentCount {
+            input_count: inputs.len(),
+            commitment_count: input_commitments.len(),
+        });
+    }
+
+    let mut hasher = DefaultHashAlgoStream::new();
+
+    hash_encoded_to(&sighash_type.get(), &mut hasher);
+    hash_encoded_to(&tx.version_byte(), &mut hasher);
+    hash_encoded_to(&tx.flags(), &mut hasher);
+
+    match sighash_type.inputs_mode() {
+        InputsMode::CommitWhoPays => {
+            hash_encoded_to(&(inputs.len() as u32), &mut hasher);
+            for input in inputs {
+                hash_encoded_to(input, &mut hasher);
+            }
+
+            hash_encoded_to(&(input_commitments.len() as u32), &mut hasher);
+            for commitment in input_commitments {
+                hash_encoded_to(commitment, &mut hasher);
+            }
+        }
+        InputsMode::AnyoneCanPay => {
+            hash_encoded_to(target_input, &mut hasher);
+            hash_encoded_to(&input_commitments[input_index], &mut hasher);
+        }
+    }
+
+    match sighash_type.outputs_mode() {
+        OutputsMode::All => {
+            hash_encoded_to(outputs, &mut hasher);
+        }
+        OutputsMode::None => {}
+        OutputsMode::Single => {
+            let output = outputs.get(input_index).ok_or(SighashError::NoOutputForSingleMode {
+                input_index,
+                output_count: outputs.len(),
+            })?;
+            hash_encoded_to(output, &mut hasher);
+        }
+    }
+
+    hash_encoded_to(&u32::MAX, &mut hasher);
+
+    Ok(hasher.finalize())
+}
+
+/// Incremental calculator of the same hash as produced by `signature_hash`, which doesn't
+/// require the whole transaction to be in memory.
+///
+/// The caller must pass all inputs, then all input commitments and then all outputs, one
+/// at a time and in the transaction's order, and then call `finalize`.
+pub struct SighashStream {
+    hasher: DefaultHashAlgoStream,
+    sighash_type: SigHashType,
+    input_index: usize,
+    input_count: usize,
+    output_count: usize,
+    inputs_processed: usize,
+    commitments_processed: usize,
+    outputs_processed: usize,
+}
+
+impl SighashStream {
+    /// Start the calculation for the input with the specified index of a V1 transaction
+    /// with the specified flags and numbers of inputs and outputs.
+    pub fn new(
+        sighash_type: SigHashType,
+        input_index: usize,
+        tx_flags: u128,
+        input_count: usize,
+        output_count: usize,
+    ) -> Result<Self, SighashError> {
+        if input_index >= input_count {
+            return Err(SighashError::InvalidInputIndex {
+                input_index,
+                input_count,
+            });
+        }
+
+        if sighash_type.outputs_mode() == OutputsMode::Single && input_index >= output_count {
+            return Err(SighashError::NoOutputForSingleMode {
+                input_index,
+                output_count,
+            });
+        }
+
+        let mut hasher = DefaultHashAlgoStream::new();
+
+        hash_encoded_to(&sighash_type.get(), &mut hasher);
+        hash_encoded_to(&TX_V1_VERSION_BYTE, &mut hasher);
+        hash_encoded_to(&tx_flags, &mut hasher);
+
+        if sighash_type.inputs_mode() == InputsMode::CommitWhoPays {
+            hash_encoded_to(&(input_count as u32), &mut hasher);
+        }
+
+        Ok(Self {
+            hasher,
+            sighash_type,
+            input_index,
+            input_count,
+            output_count,
+            inputs_processed: 0,
+            commitments_processed: 0,
+            outputs_processed: 0,
+        })
+    }
+
+    pub fn add_input(&mut self, input: &TxInput) -> Result<(), SighashError> {
+        if self.inputs_processed == self.input_count {
+            return Err(SighashError::UnexpectedInput);
+        }
+
+        match self.sighash_type.inputs_mode() {
+            InputsMode::CommitWhoPays => {
+                hash_encoded_to(input, &mut self.hasher);
+            }
+            InputsMode::AnyoneCanPay => {
+                if self.inputs_processed == self.input_index {
+                    hash_encoded_to(input, &mut self.hasher);
+                }
+            }
+        }
+
+        self.inputs_processed += 1;
+
+        if self.inputs_processed == self.input_count
+            && self.sighash_type.inputs_mode() == InputsMode::CommitWhoPays
+        {
+            // The number of commitments must be equal to the number of inputs.
+            hash_encoded_to(&(self.input_count as u32), &mut self.hasher);
+        }
+
+        Ok(())
+    }
+
+    pub fn add_input_commitment(
+        &mut self,
+        commitment: &SighashInputCommitment,
+    ) -> Result<(), SighashError> {
+        if self.inputs_processed != self.input_count
+            || self.commitments_processed == self.input_count
+        {
+            return Err(SighashError::UnexpectedInputCommitment);
+        }
+
+        match self.sighash_type.inputs_mode() {
+            InputsMode::CommitWhoPays => {
+                hash_encoded_to(commitment, &mut self.hasher);
+            }
+            InputsMode::AnyoneCanPay => {
+                if self.commitments_processed == self.input_index {
+                    hash_encoded_to(commitment, &mut self.hasher);
+                }
+            }
+        }
+
+        self.commitments_processed += 1;
+
+        if self.commitments_processed == self.input_count
+            && self.sighash_type.outputs_mode() == OutputsMode::All
+        {
+            // This is the length prefix of the encoded outputs vector.
+            hash_encoded_to(&Compact(self.output_count as u32), &mut self.hasher);
+        }
+
+        Ok(())
+    }
+
+    pub fn add_output(&mut self, output: &TxOutput) -> Result<(), SighashError> {
+        if self.commitments_processed != self.input_count
+            || self.outputs_processed == self.output_count
+        {
+            return Err(SighashError::UnexpectedOutput);
+        }
+
+        match self.sighash_type.outputs_mode() {
+            OutputsMode::All => {
+                hash_encoded_to(output, &mut self.hasher);
+            }
+            OutputsMode::None => {}
+            OutputsMode::Single => {
+                if self.outputs_processed == self.input_index {
+                    hash_encoded_to(output, &mut self.hasher);
+                }
+            }
+        }
+
+        self.outputs_processed += 1;
+
+        Ok(())
+    }
+
+    pub fn finalize(mut self) -> Result<H256, SighashError> {
+        if self.commitments_processed != self.input_count
+            || self.outputs_processed != self.output_count
+        {
+            return Err(SighashError::IncompleteStream);
+        }
+
+        hash_encoded_to(&u32::MAX, &mut self.hasher);
+
+        Ok(self.hasher.finalize())
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::{Decode, Encode};
+
+/// Determines which outputs of the transaction are committed to by a signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumIter)]
+pub enum OutputsMode {
+    /// Commit to all outputs.
+    All,
+    /// Don't commit to any outputs.
+    None,
+    /// Commit to the output with the same index as the input being signed.
+    Single,
+}
+
+/// Determines which inputs of the transaction are committed to by a signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumIter)]
+pub enum InputsMode {
+    /// Commit to all inputs.
+    CommitWhoPays,
+    /// Commit only to the input being signed, so that others can add more inputs.
+    AnyoneCanPay,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum SigHashTypeError {
+    #[error("Invalid sighash type value: {0:#04x}")]
+    InvalidValue(u8),
+}
+
+/// The sighash type, which determines what parts of the transaction are committed to
+/// by a signature.
+///
+/// The lower 7 bits define the outputs mode and must be one of `ALL`, `NONE` or `SINGLE`;
+/// the highest bit is the `ANYONECANPAY` flag, which defines the inputs mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SigHashType(u8);
+
+impl SigHashType {
+    pub const ALL: u8 = 0x01;
+    pub const NONE: u8 = 0x02;
+    pub const SINGLE: u8 = 0x03;
+    pub const ANYONECANPAY: u8 = 0x80;
+
+    const MASK_OUT: u8 = 0x7f;
+    const MASK_IN: u8 = 0x80;
+
+    pub const fn new(outputs_mode: OutputsMode, inputs_mode: InputsMode) -> Self {
+        let outputs_bits = match outputs_mode {
+            OutputsMode::All => Self::ALL,
+            OutputsMode::None => Self::NONE,
+            OutputsMode::Single => Self::SINGLE,
+        };
+        let inputs_bits = match inputs_mode {
+            InputsMode::CommitWhoPays => 0,
+            InputsMode::AnyoneCanPay => Self::ANYONECANPAY,
+        };
+
+        Self(outputs_bits | inputs_bits)
+    }
+
+    pub const fn all() -> Self {
+        Self::new(OutputsMode::All, InputsMode::CommitWhoPays)
+    }
+
+    pub const fn none() -> Self {
+        Self::new(OutputsMode::None, InputsMode::CommitWhoPays)
+    }
+
+    pub const fn single() -> Self {
+        Self::new(OutputsMode::Single, InputsMode::CommitWhoPays)
+    }
+
+    /// Return the same sighash type with the `ANYONECANPAY` flag set.
+    pub const fn with_anyone_can_pay(self) -> Self {
+        Self(self.0 | Self::ANYONECANPAY)
+    }
+
+    pub const fn get(&self) -> u8 {
+        self.0
+    }
+
+    pub const fn outputs_mode(&self) -> OutputsMode {
+        match self.0 & Self::MASK_OUT {
+            Self::NONE => OutputsMode::None,
+            Self::SINGLE => OutputsMode::Single,
+            _ => OutputsMode::All,
+        }
+    }
+
+    pub const fn inputs_mode(&self) -> InputsMode {
+        match self.0 & Self::MASK_IN {
+            Self::ANYONECANPAY => InputsMode::AnyoneCanPay,
+            _ => InputsMode::CommitWhoPays,
+        }
+    }
+}
+
+impl Default for SigHashType {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl TryFrom<u8> for SigHashType {
+    type Error = SigHashTypeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value & Self::MASK_OUT {
+            Self::ALL | Self::NONE | Self::SINGLE => Ok(Self(value)),
+            _ => Err(SigHashTypeError::InvalidValue(value)),
+        }
+    }
+}
+
+impl Encode for SigHashType {
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        self.0.encode_to(dest)
+    }
+}
+
+impl Decode for SigHashType {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        Self::try_from(u8::decode(input)?)
+            .map_err(|_| parity_scale_codec::Error::from("Invalid sighash type"))
+    }
+}
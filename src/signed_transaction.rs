@@ -0,0 +1,119 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::{Decode, Encode};
+
+use crate::{PscVec, SigHashType, Transaction, TransactionId};
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct StandardInputSignature {
+    pub sighash_type: SigHashType,
+    pub raw_signature: PscVec<u8>,
+}
+
+/// The data that authorizes the spending of a transaction input.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, strum::EnumDiscriminants)]
+#[strum_discriminants(name(InputWitnessTag), derive(strum::EnumIter))]
+pub enum InputWitness {
+    /// No signature; the optional data is only allowed for inputs that don't need a signature,
+    /// e.g. those spending `Destination::AnyoneCanSpend`.
+    #[codec(index = 0)]
+    NoSignature(Option<PscVec<u8>>),
+
+    #[codec(index = 1)]
+    Standard(StandardInputSignature),
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum SignedTransactionError {
+    #[error("Witness count {witness_count} doesn't match input count {input_count}")]
+    InvalidWitnessCount {
+        input_count: usize,
+        witness_count: usize,
+    },
+}
+
+/// A transaction together with the witnesses of its inputs, one witness per input.
+#[derive(Debug, Clone, PartialEq, Eq, Encode)]
+pub struct SignedTransaction {
+    transaction: Transaction,
+    signatures: PscVec<InputWitness>,
+}
+
+impl SignedTransaction {
+    pub fn new(
+        transaction: Transaction,
+        signatures: PscVec<InputWitness>,
+    ) -> Result<Self, SignedTransactionError> {
+        check_witness_count(&transaction, &signatures)?;
+
+        Ok(Self {
+            transaction,
+            signatures,
+        })
+    }
+
+    pub fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    pub fn signatures(&self) -> &[InputWitness] {
+        &self.signatures
+    }
+
+    pub fn into_parts(self) -> (Transaction, PscVec<InputWitness>) {
+        (self.transaction, self.signatures)
+    }
+
+    /// The id of the signed transaction, which is the id of the transaction itself, i.e.
+    /// the witnesses don't affect it.
+    pub fn get_id(&self) -> TransactionId {
+        self.transaction.get_id()
+    }
+}
+
+impl Decode for SignedTransaction {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let transaction = Transaction::decode(input)?;
+        let signatures = PscVec::<InputWitness>::decode(input)?;
+
+        check_witness_count(&transaction, &signatures)
+            .map_err(|_| parity_scale_codec::Error::from("Invalid witness count"))?;
+
+        Ok(Self {
+            transaction,
+            signatures,
+        })
+    }
+}
+
+fn check_witness_count(
+    transaction: &Transaction,
+    signatures: &[InputWitness],
+) -> Result<(), SignedTransactionError> {
+    let input_count = transaction.inputs().len();
+    let witness_count = signatures.len();
+
+    if input_count == witness_count {
+        Ok(())
+    } else {
+        Err(SignedTransactionError::InvalidWitnessCount {
+            input_count,
+            witness_count,
+        })
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Amount, ChainConfig, DelegationId, Destination, PscVec, StakePoolData, math::mul_div};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum StakePoolDataError {
+    #[error("Pledge {pledge:?} is below the minimum {min_pledge:?}")]
+    PledgeTooLow { pledge: Amount, min_pledge: Amount },
+
+    #[error("Staker destination must be a public key")]
+    StakerNotPublicKey,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum RewardDistributionError {
+    #[error("Arithmetic overflow during reward distribution")]
+    Overflow,
+}
+
+/// The split of a block reward between the staker and the delegations of a pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardDistribution {
+    pub staker_reward: Amount,
+    /// The rewards of the delegations, in the same order as they were passed in.
+    pub delegation_rewards: PscVec<(DelegationId, Amount)>,
+}
+
+impl StakePoolData {
+    /// Check the pool parameters against the same rules that Core applies to them.
+    ///
+    /// Note that the margin ratio doesn't need to be checked, because `PerThousand` can't hold
+    /// an out-of-range value.
+    pub fn validate(&self, chain_config: &ChainConfig) -> Result<(), StakePoolDataError> {
+        if self.pledge < chain_config.min_stake_pool_pledge {
+            return Err(StakePoolDataError::PledgeTooLow {
+                pledge: self.pledge,
+                min_pledge: chain_config.min_stake_pool_pledge,
+            });
+        }
+
+        match self.staker {
+            Destination::PublicKey(_) => Ok(()),
+            Destination::AnyoneCanSpend
+            | Destination::PublicKeyHash(_)
+            | Destination::ScriptHash(_)
+            | Destination::ClassicMultisig(_) => Err(StakePoolDataError::StakerNotPublicKey),
+        }
+    }
+
+    /// Split a block reward between the staker and the delegations of the pool, the same way
+    /// Core does it with reward distribution version V1:
+    /// 1. the staker gets `cost_per_block` (or the whole reward, if it's smaller);
+    /// 2. the staker gets the `margin_ratio_per_thousand` share of the rest, rounded down;
+    /// 3. what remains is split between the staker and the delegations in proportion to
+    ///    the staker's balance and the total delegated balance, and the delegations' part is then
+    ///    split between them in proportion to their balances, rounding down at each step;
+    /// 4. the staker gets everything that hasn't been given to the delegations, including
+    ///    the rounding dust.
+    ///
+    /// `staker_balance` is the pool's own balance, which includes the pledge and the rewards
+    /// that the staker has accumulated; `delegations` contains the balances of all delegations
+    /// of the pool.
+    ///
+    /// Version V0, where the staker's balance doesn't take part in step 3 and the delegations
+    /// get the whole remainder, isn't supported.
+    pub fn distribute_reward(
+        &self,
+        total_reward: Amount,
+        staker_balance: Amount,
+        delegations: &[(DelegationId, Amount)],
+    ) -> Result<RewardDistribution, RewardDistributionError> {
+        let cost_per_block = core::cmp::min(self.cost_per_block, total_reward);
+        let reward_to_distribute = total_reward
+            .checked_sub(cost_per_block)
+            .ok_or(RewardDistributionError::Overflow)?;
+
+        let margin = self
+            .margin_ratio_per_thousand
+            .scale(reward_to_distribute)
+            .ok_or(RewardDistributionError::Overflow)?;
+        let shared_reward = reward_to_distribute
+            .checked_sub(margin)
+            .ok_or(RewardDistributionError::Overflow)?;
+
+        let total_delegated = delegations
+            .iter()
+            .map(|(_, balance)| balance)
+            .sum::<Option<Amount>>()
+            .ok_or(RewardDistributionError::Overflow)?;
+        let pool_balance = staker_balance
+            .checked_add(total_delegated)
+            .ok_or(RewardDistributionError::Overflow)?;
+
+        let total_delegations_reward = if total_delegated == Amount::ZERO {
+            Amount::ZERO
+        } else {
+            mul_amounts_div(shared_reward, total_delegated, pool_balance)?
+        };
+
+        let delegation_rewards = delegations
+            .iter()
+            .map(|(delegation_id, balance)| {
+                let reward = if total_delegated == Amount::ZERO {
+                    Amount::ZERO
+                } else {
+                    mul_amounts_div(total_delegations_reward, *balance, total_delegated)?
+                };
+                Ok((*delegation_id, reward))
+            })
+            .collect::<Result<PscVec<_>, _>>()?;
+
+        let distributed = delegation_rewards
+            .iter()
+            .map(|(_, reward)| reward)
+            .sum::<Option<Amount>>()
+            .ok_or(RewardDistributionError::Overflow)?;
+        let staker_reward =
+            total_reward.checked_sub(distributed).ok_or(RewardDistributionError::Overflow)?;
+
+        Ok(RewardDistribution {
+            staker_reward,
+            delegation_rewards,
+        })
+    }
+}
+
+fn mul_amounts_div(
+    a: Amount,
+    b: Amount,
+    divisor: Amount,
+) -> Result<Amount, RewardDistributionError> {
+    mul_div(a.into_atoms(), b.into_atoms(), divisor.into_atoms())
+        .map(Amount::from_atoms)
+        .ok_or(RewardDistributionError::Overflow)
+}
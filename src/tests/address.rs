@@ -0,0 +1,253 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn test_destination_address() {
+    let pkh = PublicKeyHash(from_hex("f0e1d2c3b4a5968778695a4b3c2d1e0ff0e1d2c3"));
+    let test_vectors = [
+        (
+            ChainType::Mainnet,
+            Destination::AnyoneCanSpend,
+            "mxanyonecanspend1qqx4x7pk",
+        ),
+        (
+            ChainType::Mainnet,
+            Destination::PublicKeyHash(pkh),
+            "mtc1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvxdcf7x",
+        ),
+        (
+            ChainType::Testnet,
+            Destination::PublicKeyHash(pkh),
+            "tmt1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvvenzm0",
+        ),
+        (
+            ChainType::Regtest,
+            Destination::PublicKey(PublicKey::Secp256k1Schnorr(Secp256k1PublicKey(from_hex(
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            )))),
+            "rpmt1qgqqy7d7vel0nh9m4326qc54e6rskpczn07dktww9rv4nu5ptvt0s9ucj632tj",
+        ),
+        (
+            ChainType::Signet,
+            Destination::ScriptHash(Id::new(H256(from_hex(
+                "3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d",
+            )))),
+            "sstc1qv7zkxsflrnad3d55wfgzur0texnc2c6p8uw04k9kj3e9qtsda0y6854am3",
+        ),
+        (
+            ChainType::Mainnet,
+            Destination::ClassicMultisig(PublicKeyHash(from_hex(
+                "8603b50fdb239d6c4017500c85002ac28eae680b",
+            ))),
+            "mmtc1qjrq8dg0mv3e6mzqzagqepgq9tpgatngpvaxqeps",
+        ),
+    ];
+
+    for (chain_type, destination, address) in test_vectors {
+        assert_eq!(encode_address(chain_type, &destination).unwrap(), address);
+        assert_eq!(decode_address(chain_type, address), Ok(destination.clone()));
+        assert_eq!(
+            decode_address(chain_type, &address.to_uppercase()),
+            Ok(destination)
+        );
+    }
+}
+
+#[test]
+fn test_destination_address_errors() {
+    // Valid testnet address.
+    assert_eq!(
+        decode_address::<Destination>(
+            ChainType::Mainnet,
+            "tmt1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvvenzm0"
+        ),
+        Err(AddressError::WrongPrefix {
+            actual: "tmt".to_owned()
+        })
+    );
+
+    // Valid ClassicMultisig payload with the PublicKeyHash prefix.
+    assert_eq!(
+        decode_address::<Destination>(
+            ChainType::Mainnet,
+            "mtc1qjrq8dg0mv3e6mzqzagqepgq9tpgatngpvard6y5"
+        ),
+        Err(AddressError::PrefixMismatch {
+            expected: "mmtc",
+            actual: "mtc".to_owned()
+        })
+    );
+
+    // A pool id; its payload isn't a valid destination.
+    assert_eq!(
+        decode_address::<Destination>(
+            ChainType::Mainnet,
+            "mpool18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texs8tf8rg"
+        ),
+        Err(AddressError::WrongPrefix {
+            actual: "mpool".to_owned()
+        })
+    );
+
+    // The last character has been changed.
+    assert_eq!(
+        decode_address::<Destination>(
+            ChainType::Mainnet,
+            "mtc1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvxdcf7y"
+        ),
+        Err(AddressError::InvalidChecksum)
+    );
+
+    // Truncated public key hash.
+    assert_eq!(
+        decode_address::<Destination>(ChainType::Mainnet, "mtc1q8cwr5sly8say"),
+        Err(AddressError::InvalidPayload)
+    );
+
+    // Unknown destination type.
+    assert_eq!(
+        decode_address::<Destination>(
+            ChainType::Mainnet,
+            "mtc1qhcwr5krkjjedpmcd9dyk0pdrc8lpcwjcvjpfa3j"
+        ),
+        Err(AddressError::InvalidPayload)
+    );
+
+    for address in [
+        "",
+        "mtc",
+        "mtc1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvxdcf7b",
+        "Mtc1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvxdcf7x",
+        " mtc1q8cwr5krkjjedpmcd9dyk0pdrc8lpcwjcvxdcf7x",
+    ] {
+        assert_eq!(
+            decode_address::<Destination>(ChainType::Mainnet, address),
+            Err(AddressError::InvalidFormat),
+            "{address}"
+        );
+    }
+}
+
+#[test]
+fn test_id_and_vrf_public_key_address() {
+    let hash = H256(from_hex(
+        "3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d",
+    ));
+
+    fn check<T: Addressable + PartialEq + std::fmt::Debug>(
+        chain_type: ChainType,
+        value: T,
+        address: &str,
+    ) {
+        assert_eq!(encode_address(chain_type, &value).unwrap(), address);
+        assert_eq!(decode_address(chain_type, address), Ok(value));
+    }
+
+    check(
+        ChainType::Mainnet,
+        PoolId::new(hash),
+        "mpool18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texs8tf8rg",
+    );
+    check(
+        ChainType::Testnet,
+        DelegationId::new(hash),
+        "tdelg18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texseaht9n",
+    );
+    check(
+        ChainType::Mainnet,
+        TokenId::new(hash),
+        "mmltk18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texshsnrh6",
+    );
+    check(
+        ChainType::Regtest,
+        TokenId::new(hash),
+        "rmltk18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texsz7eynq",
+    );
+    check(
+        ChainType::Signet,
+        OrderId::new(hash),
+        "sordr18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texs7lheu0",
+    );
+
+    let vrf_public_key = VrfPublicKey::Schnorrkel(SchnorrkelPublicKey(hash.0));
+    check(
+        ChainType::Mainnet,
+        vrf_public_key.clone(),
+        "mvrfpk1qq7zkxsflrnad3d55wfgzur0texnc2c6p8uw04k9kj3e9qtsda0y660v3aq",
+    );
+    check(
+        ChainType::Testnet,
+        vrf_public_key,
+        "tvrfpk1qq7zkxsflrnad3d55wfgzur0texnc2c6p8uw04k9kj3e9qtsda0y6lh4sma",
+    );
+}
+
+#[test]
+fn test_id_address_errors() {
+    // A pool id decoded as a delegation id.
+    assert_eq!(
+        decode_address::<DelegationId>(
+            ChainType::Mainnet,
+            "mpool18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texs8tf8rg"
+        ),
+        Err(AddressError::WrongPrefix {
+            actual: "mpool".to_owned()
+        })
+    );
+
+    // A mainnet pool id decoded for testnet.
+    assert_eq!(
+        decode_address::<PoolId>(
+            ChainType::Testnet,
+            "mpool18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0texs8tf8rg"
+        ),
+        Err(AddressError::WrongPrefix {
+            actual: "mpool".to_owned()
+        })
+    );
+
+    // 33 and 31 bytes of data.
+    for address in [
+        "mpool1qq7zkxsflrnad3d55wfgzur0texnc2c6p8uw04k9kj3e9qtsda0y6uhzm8p",
+        "mpool18s435z0cultvtd9rj2qhqm67f57zkxsflrnad3d55wfgzur0tca7wl59",
+    ] {
+        assert_eq!(
+            decode_address::<PoolId>(ChainType::Mainnet, address),
+            Err(AddressError::InvalidPayload)
+        );
+    }
+}
+
+#[test]
+fn test_id_address_prefixes() {
+    for chain_type in ChainType::iter() {
+        let mut prefixes = DestinationTag::iter()
+            .map(|tag| chain_type.destination_address_prefix(tag))
+            .chain([
+                PoolIdTag::address_prefix(chain_type),
+                DelegationIdTag::address_prefix(chain_type),
+                TokenIdTag::address_prefix(chain_type),
+                OrderIdTag::address_prefix(chain_type),
+                chain_type.vrf_public_key_address_prefix(),
+            ])
+            .collect::<Vec<_>>();
+        let count = prefixes.len();
+        prefixes.sort();
+        prefixes.dedup();
+        assert_eq!(prefixes.len(), count);
+    }
+}
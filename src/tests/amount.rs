@@ -0,0 +1,220 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn test_amount_checked_arithmetic() {
+    assert_eq!(Amount::ZERO, amount(0));
+    assert_eq!(Amount::MAX, amount(u128::MAX));
+
+    assert_eq!(amount(1).checked_add(amount(2)), Some(amount(3)));
+    assert_eq!(Amount::MAX.checked_add(Amount::ZERO), Some(Amount::MAX));
+    assert_eq!(Amount::MAX.checked_add(amount(1)), None);
+
+    assert_eq!(amount(3).checked_sub(amount(2)), Some(amount(1)));
+    assert_eq!(amount(3).checked_sub(amount(3)), Some(Amount::ZERO));
+    assert_eq!(amount(2).checked_sub(amount(3)), None);
+
+    assert_eq!(amount(3).checked_mul(4), Some(amount(12)));
+    assert_eq!(Amount::MAX.checked_mul(1), Some(Amount::MAX));
+    assert_eq!(Amount::MAX.checked_mul(0), Some(Amount::ZERO));
+    assert_eq!(amount(u128::MAX / 2 + 1).checked_mul(2), None);
+
+    assert_eq!(amount(13).checked_div(4), Some(amount(3)));
+    assert_eq!(amount(3).checked_div(4), Some(Amount::ZERO));
+    assert_eq!(amount(3).checked_div(0), None);
+}
+
+#[test]
+fn test_amount_saturating_arithmetic() {
+    assert_eq!(amount(1).saturating_add(amount(2)), amount(3));
+    assert_eq!(Amount::MAX.saturating_add(amount(1)), Amount::MAX);
+
+    assert_eq!(amount(3).saturating_sub(amount(2)), amount(1));
+    assert_eq!(amount(2).saturating_sub(amount(3)), Amount::ZERO);
+
+    assert_eq!(amount(3).saturating_mul(4), amount(12));
+    assert_eq!(amount(u128::MAX / 2 + 1).saturating_mul(2), Amount::MAX);
+}
+
+#[test]
+fn test_amount_wrapping_arithmetic() {
+    assert_eq!(amount(1).wrapping_add(amount(2)), amount(3));
+    assert_eq!(Amount::MAX.wrapping_add(amount(1)), Amount::ZERO);
+    assert_eq!(Amount::MAX.wrapping_add(Amount::MAX), amount(u128::MAX - 1));
+
+    assert_eq!(amount(3).wrapping_sub(amount(2)), amount(1));
+    assert_eq!(amount(2).wrapping_sub(amount(3)), Amount::MAX);
+
+    assert_eq!(amount(3).wrapping_mul(4), amount(12));
+    assert_eq!(amount(u128::MAX / 2 + 1).wrapping_mul(2), Amount::ZERO);
+    assert_eq!(Amount::MAX.wrapping_mul(3), amount(u128::MAX - 2));
+}
+
+#[test]
+fn test_amount_sum() {
+    let amounts = [amount(1), amount(2), amount(3)];
+    assert_eq!(amounts.iter().sum::<Option<Amount>>(), Some(amount(6)));
+    assert_eq!(amounts.into_iter().sum::<Option<Amount>>(), Some(amount(6)));
+
+    assert_eq!(
+        std::iter::empty::<Amount>().sum::<Option<Amount>>(),
+        Some(Amount::ZERO)
+    );
+
+    let amounts = [amount(1), Amount::MAX, amount(0)];
+    assert_eq!(amounts.iter().sum::<Option<Amount>>(), None);
+
+    assert_eq!(
+        Amount::checked_sum([Some(amount(1)), Some(amount(2)), Some(amount(3))]),
+        Some(amount(6))
+    );
+    assert_eq!(Amount::checked_sum([]), Some(Amount::ZERO));
+    assert_eq!(Amount::checked_sum([Some(amount(1)), None]), None);
+    assert_eq!(
+        Amount::checked_sum([Some(Amount::MAX), Some(amount(1))]),
+        None
+    );
+    assert_eq!(
+        Amount::checked_sum([amount(5), amount(3)].map(|a| a.checked_sub(amount(4)))),
+        None
+    );
+}
+
+#[test]
+fn test_amount_into_fixedpoint_str() {
+    let check = |atoms: AmountUIntType, decimals: u8, expected: &str| {
+        let mut buf = [0u8; 300];
+        assert_eq!(
+            amount(atoms).into_fixedpoint_str(decimals, &mut buf),
+            Some(expected)
+        );
+    };
+
+    check(0, 0, "0");
+    check(0, 11, "0");
+    check(1, 0, "1");
+    check(1250, 2, "12.5");
+    check(1200, 2, "12");
+    check(1, 11, "0.00000000001");
+    check(1_250_000_000_000, 11, "12.5");
+    check(123_456_789, 3, "123456.789");
+    check(100_000_000_000, 11, "1");
+    check(u128::MAX, 0, "340282366920938463463374607431768211455");
+    check(u128::MAX, 18, "340282366920938463463.374607431768211455");
+    check(u128::MAX, 39, "0.340282366920938463463374607431768211455");
+    check(u128::MAX, 40, "0.0340282366920938463463374607431768211455");
+    check(5, 255, &format!("0.{}5", "0".repeat(254)));
+}
+
+#[test]
+fn test_amount_into_fixedpoint_str_buffer_size() {
+    for decimals in [0, 1, 11, 18, 38, 39, 40, 255] {
+        let max_len = Amount::fixedpoint_str_max_len(decimals);
+
+        for atoms in [0, 1, 123, u128::MAX / 7, u128::MAX] {
+            let mut buf = vec![0u8; max_len];
+            let len = amount(atoms).into_fixedpoint_str(decimals, &mut buf).unwrap().len();
+            assert!(len <= max_len);
+
+            let mut buf = vec![0u8; len];
+            assert!(amount(atoms).into_fixedpoint_str(decimals, &mut buf).is_some());
+
+            let mut buf = vec![0u8; len - 1];
+            assert_eq!(amount(atoms).into_fixedpoint_str(decimals, &mut buf), None);
+        }
+    }
+
+    assert_eq!(Amount::fixedpoint_str_max_len(0), 40);
+    assert_eq!(Amount::fixedpoint_str_max_len(38), 40);
+    assert_eq!(Amount::fixedpoint_str_max_len(39), 41);
+    assert_eq!(Amount::fixedpoint_str_max_len(255), 257);
+}
+
+#[test]
+fn test_amount_from_fixedpoint_str() {
+    let check = |s: &str, decimals: u8, expected: Option<AmountUIntType>| {
+        assert_eq!(
+            Amount::from_fixedpoint_str(s, decimals),
+            expected.map(amount),
+            "s = {s:?}, decimals = {decimals}"
+        );
+    };
+
+    check("0", 0, Some(0));
+    check("0", 11, Some(0));
+    check("000", 2, Some(0));
+    check("12.5", 2, Some(1250));
+    check("12.50", 2, Some(1250));
+    check("012.5", 2, Some(1250));
+    check("12", 11, Some(1_200_000_000_000));
+    check("0.00000000001", 11, Some(1));
+    check("0.000", 255, Some(0));
+    check(
+        "340282366920938463463374607431768211455",
+        0,
+        Some(u128::MAX),
+    );
+    check(
+        "340282366920938463463.374607431768211455",
+        18,
+        Some(u128::MAX),
+    );
+    check(
+        "0.340282366920938463463374607431768211455",
+        39,
+        Some(u128::MAX),
+    );
+
+    // Extra precision
+    check("12.5", 0, None);
+    check("12.51", 1, None);
+    check("0.000000000001", 11, None);
+    check("1.0000000000001", 11, None);
+    check("12.500", 2, None);
+    check("0.0", 0, None);
+    check("1.000000000000", 11, None);
+
+    // Overflow
+    check("340282366920938463463374607431768211456", 0, None);
+    check("340282366920938463463.374607431768211456", 18, None);
+    check("340282366920938463463.374607431768211455", 19, None);
+    check("1", 39, None);
+    check("0.1", 255, None);
+
+    // Malformed
+    for s in [
+        "", ".", "-1", "+1", "-0", " 1", "1 ", "1.5 ", "1,5", "1.2.3", ".5", "5.", "1e3", "0x10",
+        "1_000", "abc", "１",
+    ] {
+        check(s, 2, None);
+    }
+}
+
+#[test]
+fn test_amount_fixedpoint_str_roundtrip() {
+    let mut buf = [0u8; 300];
+
+    for decimals in [0, 1, 2, 11, 18, 39, 40, 255] {
+        for atoms in [0, 1, 10, 1250, 1_000_000, 123_456_789_012, u128::MAX / 3, u128::MAX] {
+            let s = amount(atoms).into_fixedpoint_str(decimals, &mut buf).unwrap();
+            assert_eq!(
+                Amount::from_fixedpoint_str(s, decimals),
+                Some(amount(atoms))
+            );
+        }
+    }
+}
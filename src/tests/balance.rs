@@ -0,0 +1,450 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn dest() -> Destination {
+    Destination::PublicKeyHash(PublicKeyHash::repeat_byte(0x11))
+}
+
+fn utxo_input(index: u32) -> TxInput {
+    TxInput::Utxo(UtxoOutPoint::new(
+        OutPointSourceId::Transaction(repeated_byte_id(0x22)),
+        index,
+    ))
+}
+
+fn transfer(value: OutputValue) -> TxOutput {
+    TxOutput::Transfer(value, dest())
+}
+
+#[test]
+fn test_output_value_currency() {
+    let token_id = repeated_byte_id(1);
+
+    let coin = OutputValue::Coin(amount(5));
+    assert_eq!(coin.currency(), Currency::Coin);
+    assert_eq!(coin.amount(), amount(5));
+    assert_eq!(Currency::Coin.into_output_value(amount(5)), coin);
+
+    let token = OutputValue::TokenV1(token_id, amount(7));
+    assert_eq!(token.currency(), Currency::Token(token_id));
+    assert_eq!(token.amount(), amount(7));
+    assert_eq!(
+        Currency::Token(token_id).into_output_value(amount(7)),
+        token
+    );
+}
+
+#[test]
+fn test_value_accumulator() {
+    let token1 = Currency::Token(repeated_byte_id(1));
+    let token2 = Currency::Token(repeated_byte_id(2));
+
+    let mut acc = ValueAccumulator::new();
+    assert!(acc.is_empty());
+    assert_eq!(acc.get(Currency::Coin), Amount::ZERO);
+
+    acc.add(&OutputValue::Coin(amount(10))).unwrap();
+    acc.add(&OutputValue::TokenV1(repeated_byte_id(2), amount(3))).unwrap();
+    acc.add_amount(token1, amount(4)).unwrap();
+    acc.add_amount(Currency::Coin, amount(5)).unwrap();
+
+    assert_eq!(acc.get(Currency::Coin), amount(15));
+    assert_eq!(acc.get(token1), amount(4));
+    assert_eq!(acc.get(token2), amount(3));
+    assert_eq!(
+        acc.iter().collect::<Vec<_>>(),
+        vec![(Currency::Coin, amount(15)), (token1, amount(4)), (token2, amount(3))]
+    );
+
+    assert_eq!(
+        acc.add_amount(Currency::Coin, Amount::MAX),
+        Err(BalanceError::Overflow(Currency::Coin))
+    );
+    assert_eq!(
+        acc.sub_amount(token1, amount(5)),
+        Err(BalanceError::InsufficientBalance {
+            currency: token1,
+            available: amount(4),
+            required: amount(5),
+        })
+    );
+    assert_eq!(
+        acc.sub(&OutputValue::TokenV1(repeated_byte_id(3), amount(1))),
+        Err(BalanceError::InsufficientBalance {
+            currency: Currency::Token(repeated_byte_id(3)),
+            available: Amount::ZERO,
+            required: amount(1),
+        })
+    );
+    // Failed operations don't change the balances.
+    assert_eq!(acc.get(Currency::Coin), amount(15));
+    assert_eq!(acc.get(token1), amount(4));
+
+    // Zero balances are removed.
+    acc.sub_amount(token1, amount(4)).unwrap();
+    acc.add_amount(token1, Amount::ZERO).unwrap();
+    assert_eq!(
+        acc.iter().collect::<Vec<_>>(),
+        vec![(Currency::Coin, amount(15)), (token2, amount(3))]
+    );
+
+    let mut other = ValueAccumulator::new();
+    other.add_amount(Currency::Coin, amount(15)).unwrap();
+    other.add_amount(token2, amount(3)).unwrap();
+    assert_eq!(acc, other);
+
+    // Subtracting several currencies is atomic: the coin balance is sufficient, but since the
+    // token one isn't, nothing is subtracted.
+    let mut too_much = other.clone();
+    too_much.add_amount(token2, amount(1)).unwrap();
+    assert_eq!(
+        acc.sub_all(&too_much),
+        Err(BalanceError::InsufficientBalance {
+            currency: token2,
+            available: amount(3),
+            required: amount(4),
+        })
+    );
+    assert_eq!(acc, other);
+
+    acc.sub_all(&other).unwrap();
+    assert!(acc.is_empty());
+    assert_eq!(acc, ValueAccumulator::new());
+}
+
+#[test]
+fn test_transaction_surplus() {
+    let token1 = repeated_byte_id(1);
+    let token2 = repeated_byte_id(2);
+    let nft = repeated_byte_id(3);
+    let pool_id = repeated_byte_id(0x33);
+    let delegation_id = repeated_byte_id(0x44);
+
+    let tx = Transaction::V1(TransactionV1::new(
+        0,
+        vec![
+            utxo_input(0),
+            utxo_input(1),
+            utxo_input(2),
+            utxo_input(3),
+            TxInput::Account(AccountOutPoint {
+                nonce: AccountNonce(0),
+                spending: AccountSpending::DelegationBalance(delegation_id, amount(500)),
+            }),
+            TxInput::AccountCommand(
+                AccountNonce(1),
+                AccountCommand::MintTokens(token2, amount(1000)),
+            ),
+            TxInput::AccountCommand(AccountNonce(2), AccountCommand::LockTokenSupply(token1)),
+        ],
+        vec![
+            transfer(OutputValue::Coin(amount(1000))),
+            TxOutput::LockThenTransfer(
+                OutputValue::TokenV1(token1, amount(30)),
+                dest(),
+                OutputTimeLock::ForBlockCount(BlocksCount(10)),
+            ),
+            TxOutput::Burn(OutputValue::TokenV1(token1, amount(20))),
+            TxOutput::DelegateStaking(amount(300), delegation_id),
+            transfer(OutputValue::TokenV1(token2, amount(1000))),
+            transfer(OutputValue::TokenV1(nft, amount(1))),
+            TxOutput::CreateDelegationId(dest(), pool_id),
+            TxOutput::DataDeposit(vec![1, 2, 3]),
+        ],
+    ));
+    let input_commitments = vec![
+        SighashInputCommitment::Utxo(transfer(OutputValue::Coin(amount(2000)))),
+        SighashInputCommitment::Utxo(TxOutput::Htlc(
+            OutputValue::TokenV1(token1, amount(60)),
+            HashedTimelockContract {
+                secret_hash: HtlcSecretHash::zero(),
+                spend_key: dest(),
+                refund_timelock: OutputTimeLock::ForSeconds(SecondsCount(100)),
+                refund_key: dest(),
+            },
+        )),
+        SighashInputCommitment::Utxo(TxOutput::IssueNft(
+            nft,
+            NftIssuance::V0(NftIssuanceV0 {
+                creator: None,
+                name: b"Name".to_vec(),
+                description: b"Description".to_vec(),
+                ticker: b"NFT".to_vec(),
+                icon_uri: Vec::new(),
+                additional_metadata_uri: Vec::new(),
+                media_uri: Vec::new(),
+                media_hash: vec![0; 32],
+            }),
+            dest(),
+        )),
+        SighashInputCommitment::ProduceBlockFromStakeUtxo {
+            utxo: TxOutput::ProduceBlockFromStake(dest(), pool_id),
+            staker_balance: amount(150),
+        },
+        SighashInputCommitment::None,
+        SighashInputCommitment::None,
+        SighashInputCommitment::None,
+    ];
+
+    let surplus = transaction_surplus(&tx, &input_commitments).unwrap();
+    // Coins: 2000 + 150 + 500 - 1000 - 300
+    // Token1: 60 - 30 - 20
+    assert_eq!(
+        surplus.iter().collect::<Vec<_>>(),
+        vec![(Currency::Coin, amount(1350)), (Currency::Token(token1), amount(10))]
+    );
+}
+
+#[test]
+fn test_transaction_surplus_order_fill() {
+    let token1 = repeated_byte_id(1);
+    let order_id = repeated_byte_id(0x66);
+
+    // Pay 25 coins (plus a 1 coin fee) for a quarter of the 20000 tokens given by the order.
+    let tx = Transaction::V1(TransactionV1::new(
+        0,
+        vec![
+            utxo_input(0),
+            TxInput::OrderAccountCommand(OrderAccountCommand::FillOrder(order_id, amount(25))),
+        ],
+        vec![
+            transfer(OutputValue::TokenV1(token1, amount(4_000))),
+            transfer(OutputValue::Coin(amount(74))),
+        ],
+    ));
+    let input_commitments = vec![
+        SighashInputCommitment::Utxo(transfer(OutputValue::Coin(amount(100)))),
+        SighashInputCommitment::FillOrderAccountCommand {
+            initially_asked: OutputValue::Coin(amount(100)),
+            initially_given: OutputValue::TokenV1(token1, amount(20_000)),
+        },
+    ];
+
+    let surplus = transaction_surplus(&tx, &input_commitments).unwrap();
+    assert_eq!(
+        surplus.iter().collect::<Vec<_>>(),
+        vec![(Currency::Coin, amount(1)), (Currency::Token(token1), amount(1_000))]
+    );
+}
+
+#[test]
+fn test_transaction_surplus_order_conclusion() {
+    let token1 = repeated_byte_id(1);
+    let order_id = repeated_byte_id(0x66);
+
+    // The order asked for 100 coins and gave 20000 tokens, 25 coins have been paid to it so far.
+    let make_tx = |outputs| {
+        Transaction::V1(TransactionV1::new(
+            0,
+            vec![
+                utxo_input(0),
+                TxInput::OrderAccountCommand(OrderAccountCommand::ConcludeOrder(order_id)),
+            ],
+            outputs,
+        ))
+    };
+    let input_commitments = vec![
+        SighashInputCommitment::Utxo(transfer(OutputValue::Coin(amount(2)))),
+        SighashInputCommitment::ConcludeOrderAccountCommand {
+            initially_asked: OutputValue::Coin(amount(100)),
+            initially_given: OutputValue::TokenV1(token1, amount(20_000)),
+            ask_balance: amount(75),
+            give_balance: amount(15_000),
+        },
+    ];
+
+    let tx = make_tx(vec![
+        transfer(OutputValue::TokenV1(token1, amount(15_000))),
+        transfer(OutputValue::Coin(amount(26))),
+    ]);
+    let surplus = transaction_surplus(&tx, &input_commitments).unwrap();
+    assert_eq!(
+        surplus.iter().collect::<Vec<_>>(),
+        vec![(Currency::Coin, amount(1))]
+    );
+
+    // Taking more than the order holds
+    let tx = make_tx(vec![
+        transfer(OutputValue::TokenV1(token1, amount(15_001))),
+        transfer(OutputValue::Coin(amount(26))),
+    ]);
+    assert_eq!(
+        transaction_surplus(&tx, &input_commitments),
+        Err(BalanceError::InsufficientBalance {
+            currency: Currency::Token(token1),
+            available: amount(15_000),
+            required: amount(15_001),
+        })
+    );
+    let tx = make_tx(vec![
+        transfer(OutputValue::TokenV1(token1, amount(15_000))),
+        transfer(OutputValue::Coin(amount(28))),
+    ]);
+    assert_eq!(
+        transaction_surplus(&tx, &input_commitments),
+        Err(BalanceError::InsufficientBalance {
+            currency: Currency::Coin,
+            available: amount(27),
+            required: amount(28),
+        })
+    );
+
+    // Invalid commitments
+    let tx = make_tx(Vec::new());
+    assert_eq!(
+        transaction_surplus(
+            &tx,
+            &[
+                input_commitments[0].clone(),
+                SighashInputCommitment::ConcludeOrderAccountCommand {
+                    initially_asked: OutputValue::Coin(amount(100)),
+                    initially_given: OutputValue::TokenV1(token1, amount(20_000)),
+                    ask_balance: amount(101),
+                    give_balance: amount(15_000),
+                },
+            ]
+        ),
+        Err(BalanceError::InvalidOrderConclusion { input_index: 1 })
+    );
+    assert_eq!(
+        transaction_surplus(
+            &tx,
+            &[
+                input_commitments[0].clone(),
+                SighashInputCommitment::FillOrderAccountCommand {
+                    initially_asked: OutputValue::Coin(amount(100)),
+                    initially_given: OutputValue::TokenV1(token1, amount(20_000)),
+                },
+            ]
+        ),
+        Err(BalanceError::InvalidInputCommitment { input_index: 1 })
+    );
+}
+
+#[test]
+fn test_transaction_surplus_errors() {
+    let token1 = repeated_byte_id(1);
+
+    let make_tx = |inputs, outputs| Transaction::V1(TransactionV1::new(0, inputs, outputs));
+
+    // Outputs take more than the inputs provide.
+    let tx = make_tx(
+        vec![utxo_input(0)],
+        vec![
+            TxOutput::CreateOrder(OrderData {
+                conclude_key: dest(),
+                ask: OutputValue::TokenV1(token1, amount(1)),
+                give: OutputValue::Coin(amount(80)),
+            }),
+            TxOutput::CreateStakePool(
+                repeated_byte_id(0x33),
+                StakePoolData {
+                    pledge: amount(30),
+                    staker: dest(),
+                    vrf_public_key: VrfPublicKey::Schnorrkel(SchnorrkelPublicKey([0x55; 32])),
+                    decommission_key: dest(),
+                    margin_ratio_per_thousand: PerThousand::new(100).unwrap(),
+                    cost_per_block: amount(0),
+                },
+            ),
+        ],
+    );
+    let commitments = vec![SighashInputCommitment::Utxo(transfer(OutputValue::Coin(amount(100))))];
+    assert_eq!(
+        transaction_surplus(&tx, &commitments),
+        Err(BalanceError::InsufficientBalance {
+            currency: Currency::Coin,
+            available: amount(100),
+            required: amount(110),
+        })
+    );
+
+    // Wrong number of commitments.
+    assert_eq!(
+        transaction_surplus(&tx, &[]),
+        Err(BalanceError::InvalidInputCommitmentCount {
+            input_count: 1,
+            commitment_count: 0,
+        })
+    );
+
+    // A UTXO input needs a commitment with a spendable output.
+    for commitment in [
+        SighashInputCommitment::None,
+        SighashInputCommitment::Utxo(TxOutput::Burn(OutputValue::Coin(amount(100)))),
+        SighashInputCommitment::Utxo(TxOutput::DataDeposit(Vec::new())),
+    ] {
+        let tx = make_tx(vec![utxo_input(0)], Vec::new());
+        assert_eq!(
+            transaction_surplus(&tx, &[commitment]),
+            Err(BalanceError::InvalidInputCommitment { input_index: 0 })
+        );
+    }
+
+    // Legacy order commands are not supported.
+    let order_id = repeated_byte_id(0x66);
+    for input in [
+        TxInput::AccountCommand(
+            AccountNonce(0),
+            AccountCommand::FillOrder(order_id, amount(1), dest()),
+        ),
+        TxInput::AccountCommand(AccountNonce(0), AccountCommand::ConcludeOrder(order_id)),
+    ] {
+        let tx = make_tx(vec![utxo_input(0), input], Vec::new());
+        let commitments = vec![
+            SighashInputCommitment::Utxo(transfer(OutputValue::Coin(amount(100)))),
+            SighashInputCommitment::None,
+        ];
+        assert_eq!(
+            transaction_surplus(&tx, &commitments),
+            Err(BalanceError::UnsupportedInput { input_index: 1 })
+        );
+    }
+
+    // An order fill needs the corresponding commitment and a valid fill amount.
+    let fill_input =
+        TxInput::OrderAccountCommand(OrderAccountCommand::FillOrder(order_id, amount(1)));
+    for commitment in [
+        SighashInputCommitment::None,
+        SighashInputCommitment::Utxo(transfer(OutputValue::Coin(amount(100)))),
+    ] {
+        let tx = make_tx(vec![fill_input.clone()], Vec::new());
+        assert_eq!(
+            transaction_surplus(&tx, &[commitment]),
+            Err(BalanceError::InvalidInputCommitment { input_index: 0 })
+        );
+    }
+    let tx = make_tx(vec![fill_input], Vec::new());
+    let commitment = SighashInputCommitment::FillOrderAccountCommand {
+        initially_asked: OutputValue::Coin(Amount::ZERO),
+        initially_given: OutputValue::TokenV1(token1, amount(100)),
+    };
+    assert_eq!(
+        transaction_surplus(&tx, &[commitment]),
+        Err(BalanceError::InvalidOrderFill { input_index: 0 })
+    );
+
+    // Freezing an order doesn't affect the balance.
+    let tx = make_tx(
+        vec![TxInput::OrderAccountCommand(OrderAccountCommand::FreezeOrder(order_id))],
+        Vec::new(),
+    );
+    assert_eq!(
+        transaction_surplus(&tx, &[SighashInputCommitment::None]),
+        Ok(ValueAccumulator::new())
+    );
+}
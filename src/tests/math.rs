@@ -0,0 +1,42 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::math::mul_div;
+
+#[test]
+fn test_mul_div() {
+    let max = u128::MAX;
+
+    assert_eq!(mul_div(0, 0, 1), Some(0));
+    assert_eq!(mul_div(1, 1, 0), None);
+    assert_eq!(mul_div(0, 0, 0), None);
+    assert_eq!(mul_div(1, 3, 7), Some(0));
+    assert_eq!(mul_div(1234567, 987654321, 1000), Some(1219325432114));
+    assert_eq!(mul_div(max, max, max), Some(max));
+    assert_eq!(mul_div(max, max - 1, max), Some(max - 1));
+    assert_eq!(
+        mul_div(max, 3, 7),
+        Some(145835300108973627198589117470757804909)
+    );
+    assert_eq!(
+        mul_div(10u128.pow(30), 10u128.pow(25), 3 * 10u128.pow(20)),
+        Some(33333333333333333333333333333333333)
+    );
+    // 2^127 * 4 / 2 = 2^128
+    assert_eq!(mul_div(1 << 127, 4, 2), None);
+    assert_eq!(mul_div(max, max, max - 1), None);
+    assert_eq!(mul_div(max, 2, 1), None);
+    assert_eq!(mul_div(max, 1, 1), Some(max));
+}
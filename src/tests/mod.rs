@@ -16,12 +16,21 @@
 #[cfg(test)]
 extern crate std;
 
+mod address;
+mod amount;
+mod balance;
+mod math;
+mod orders;
+mod sighash;
+mod staking;
+mod timelock;
+mod tokens;
 mod utils;
 
 use std::prelude::v1::*;
 
 use hex::FromHex;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{DecodeAll as _, Encode};
 use strum::IntoEnumIterator as _;
 
 use crate::tests::utils::{
@@ -32,7 +41,7 @@ use crate::tests::utils::{
     SCALE_CODEC_COMPACT_ENC_11_BYTE_VAL_START, SCALE_CODEC_COMPACT_ENC_12_BYTE_VAL_START,
     SCALE_CODEC_COMPACT_ENC_13_BYTE_VAL_START, SCALE_CODEC_COMPACT_ENC_14_BYTE_VAL_START,
     SCALE_CODEC_COMPACT_ENC_15_BYTE_VAL_START, SCALE_CODEC_COMPACT_ENC_16_BYTE_VAL_START,
-    SCALE_CODEC_COMPACT_ENC_17_BYTE_VAL_START,
+    SCALE_CODEC_COMPACT_ENC_17_BYTE_VAL_START, amount, repeated_byte_id,
 };
 
 use super::*;
@@ -261,6 +270,62 @@ fn test_block_timestamp_encoding() {
     assert_eq!(encoded_val, "130000000000000001");
 }
 
+#[test]
+fn test_block_height_arithmetic() {
+    assert_eq!(BlockHeight::zero(), BlockHeight(0));
+    assert_eq!(BlockHeight(5).next_height(), Some(BlockHeight(6)));
+    assert_eq!(BlockHeight(u64::MAX).next_height(), None);
+
+    assert_eq!(BlockHeight(5) + BlocksCount(3), Some(BlockHeight(8)));
+    assert_eq!(
+        BlockHeight(u64::MAX) + BlocksCount(0),
+        Some(BlockHeight(u64::MAX))
+    );
+    assert_eq!(BlockHeight(u64::MAX) + BlocksCount(1), None);
+
+    assert_eq!(BlockHeight(5) - BlocksCount(3), Some(BlockHeight(2)));
+    assert_eq!(BlockHeight(5) - BlocksCount(6), None);
+
+    assert_eq!(BlockHeight(5) - BlockHeight(3), Some(BlocksCount(2)));
+    assert_eq!(BlockHeight(5) - BlockHeight(5), Some(BlocksCount::zero()));
+    assert_eq!(BlockHeight(3) - BlockHeight(5), None);
+
+    assert_eq!(BlocksCount::zero(), BlocksCount(0));
+    assert_eq!(BlocksCount(5) + BlocksCount(3), Some(BlocksCount(8)));
+    assert_eq!(BlocksCount(u64::MAX) + BlocksCount(1), None);
+    assert_eq!(BlocksCount(5) - BlocksCount(3), Some(BlocksCount(2)));
+    assert_eq!(BlocksCount(3) - BlocksCount(5), None);
+
+    assert_eq!(BlockHeight(123456).to_string(), "123456");
+    assert_eq!(BlocksCount(42).to_string(), "42");
+}
+
+#[test]
+fn test_block_timestamp_arithmetic() {
+    let ts = BlockTimestamp::from_int_seconds;
+
+    assert_eq!(BlockTimestamp::zero(), ts(0));
+    assert_eq!(ts(1000).as_int_seconds(), 1000);
+
+    assert_eq!(ts(1000) + SecondsCount(500), Some(ts(1500)));
+    assert_eq!(ts(u64::MAX) + SecondsCount(1), None);
+
+    assert_eq!(ts(1000) - SecondsCount(500), Some(ts(500)));
+    assert_eq!(ts(1000) - SecondsCount(1001), None);
+
+    assert_eq!(ts(1000) - ts(400), Some(SecondsCount(600)));
+    assert_eq!(ts(400) - ts(1000), None);
+
+    assert_eq!(SecondsCount::zero(), SecondsCount(0));
+    assert_eq!(SecondsCount(5) + SecondsCount(3), Some(SecondsCount(8)));
+    assert_eq!(SecondsCount(u64::MAX) + SecondsCount(1), None);
+    assert_eq!(SecondsCount(5) - SecondsCount(3), Some(SecondsCount(2)));
+    assert_eq!(SecondsCount(3) - SecondsCount(5), None);
+
+    assert_eq!(ts(1700000000).to_string(), "1700000000");
+    assert_eq!(SecondsCount(60).to_string(), "60");
+}
+
 #[test]
 fn test_public_key_hash_encoding() {
     let val = PublicKeyHash(from_hex("1122334455667788990011223344556677889900"));
@@ -369,9 +434,92 @@ fn test_destination_encoding() {
 
 #[test]
 fn test_per_thousand_encoding() {
-    let val = PerThousand(123);
+    let val = PerThousand::new(123).unwrap();
     let encoded_val = hex_encode(&val);
     assert_eq!(encoded_val, "7b00");
+
+    let val = PerThousand::new(1000).unwrap();
+    let encoded_val = hex_encode(&val);
+    assert_eq!(encoded_val, "e803");
+
+    assert_eq!(
+        PerThousand::decode_all(&mut from_hex::<Vec<u8>>("e803").as_slice()),
+        Ok(val)
+    );
+    assert!(PerThousand::decode_all(&mut from_hex::<Vec<u8>>("e903").as_slice()).is_err());
+    assert!(PerThousand::decode_all(&mut from_hex::<Vec<u8>>("ffff").as_slice()).is_err());
+}
+
+#[test]
+fn test_per_thousand() {
+    assert_eq!(PerThousand::new(0).map(|v| v.value()), Some(0));
+    assert_eq!(PerThousand::new(1000).map(|v| v.value()), Some(1000));
+    assert_eq!(PerThousand::new(1001), None);
+    assert_eq!(PerThousand::try_from(500).map(|v| v.value()), Ok(500));
+    assert_eq!(
+        PerThousand::try_from(1001),
+        Err(PerThousandError::OutOfRange(1001))
+    );
+
+    let scale = |per_thousand: u16, atoms: AmountUIntType| {
+        PerThousand::new(per_thousand)
+            .unwrap()
+            .scale(Amount::from_atoms(atoms))
+            .map(|amount| amount.into_atoms())
+    };
+    assert_eq!(scale(0, 12345), Some(0));
+    assert_eq!(scale(1000, 12345), Some(12345));
+    assert_eq!(scale(125, 1000), Some(125));
+    assert_eq!(scale(125, 12345), Some(1543));
+    assert_eq!(scale(1, 999), Some(0));
+    assert_eq!(scale(1000, u128::MAX), Some(u128::MAX));
+    assert_eq!(scale(500, u128::MAX), Some(u128::MAX / 2));
+    assert_eq!(
+        scale(999, u128::MAX),
+        Some(u128::MAX / 1000 * 999 + 455 * 999 / 1000)
+    );
+}
+
+#[test]
+fn test_per_thousand_percentage_str() {
+    let mut buf = [0u8; PerThousand::PERCENTAGE_STR_MAX_LEN];
+    for (value, expected) in [
+        (0, "0%"),
+        (1, "0.1%"),
+        (10, "1%"),
+        (125, "12.5%"),
+        (999, "99.9%"),
+        (1000, "100%"),
+    ] {
+        let val = PerThousand::new(value).unwrap();
+        assert_eq!(val.into_percentage_str(&mut buf), Some(expected));
+        assert_eq!(PerThousand::from_percentage_str(expected), Some(val));
+    }
+
+    assert_eq!(
+        PerThousand::new(125).unwrap().into_percentage_str(&mut [0u8; 4]),
+        None
+    );
+    assert_eq!(
+        PerThousand::new(1).unwrap().into_percentage_str(&mut [0u8; 3]),
+        None
+    );
+
+    assert_eq!(
+        PerThousand::from_percentage_str("012%"),
+        PerThousand::new(120)
+    );
+    assert_eq!(
+        PerThousand::from_percentage_str("100.0%"),
+        PerThousand::new(1000)
+    );
+
+    for s in [
+        "", "%", "12.5", "12.5 %", " 12.5%", "12.5%%", "%12.5", "-1%", "+1%", "12.55%", "12.50%",
+        "100.1%", "101%", "65536%", "1e2%", ".5%",
+    ] {
+        assert_eq!(PerThousand::from_percentage_str(s), None, "s = {s:?}");
+    }
 }
 
 #[test]
@@ -698,7 +846,7 @@ fn test_stake_pool_data_encoding() {
             "1122334455667788990011223344556677889900112233445566778899001122",
         ))),
         decommission_key: Destination::AnyoneCanSpend,
-        margin_ratio_per_thousand: PerThousand(123),
+        margin_ratio_per_thousand: PerThousand::new(123).unwrap(),
         cost_per_block: Amount::from_atoms(123),
     };
     let encoded_val = hex_encode(&val);
@@ -845,7 +993,7 @@ fn test_tx_output_encoding() {
                             "1122334455667788990011223344556677889900112233445566778899001122",
                         ))),
                         decommission_key: Destination::AnyoneCanSpend,
-                        margin_ratio_per_thousand: PerThousand(123),
+                        margin_ratio_per_thousand: PerThousand::new(123).unwrap(),
                         cost_per_block: Amount::from_atoms(123),
                     },
                 );
@@ -1130,3 +1278,363 @@ fn test_tx_input_encoding() {
         }
     }
 }
+
+#[test]
+fn test_transaction_encoding() {
+    for tag in TransactionTag::iter() {
+        match tag {
+            TransactionTag::V1 => {
+                let val = Transaction::V1(TransactionV1::new(
+                    SCALE_CODEC_COMPACT_ENC_2_BYTE_VAL_START.into(),
+                    vec![
+                        TxInput::Utxo(UtxoOutPoint::new(
+                            OutPointSourceId::Transaction(Id::new(H256(from_hex(
+                                "1122334455667788990011223344556677889900112233445566778899001122",
+                            )))),
+                            123,
+                        )),
+                        TxInput::AccountCommand(
+                            AccountNonce(123),
+                            AccountCommand::UnmintTokens(Id::new(H256(from_hex(
+                                "1122334455667788990011223344556677889900112233445566778899001122",
+                            )))),
+                        ),
+                    ],
+                    vec![
+                        TxOutput::Transfer(
+                            OutputValue::Coin(Amount::from_atoms(123)),
+                            Destination::AnyoneCanSpend,
+                        ),
+                        TxOutput::Burn(OutputValue::Coin(Amount::from_atoms(123))),
+                    ],
+                ));
+                let encoded_val = hex_encode(&val);
+                assert_eq!(
+                    encoded_val,
+                    concat!(
+                        "01",
+                        "0101",
+                        "08",
+                        "000011223344556677889900112233445566778899001122334455667788990011227b000000",
+                        "02ed01011122334455667788990011223344556677889900112233445566778899001122",
+                        "08",
+                        "0000ed0100",
+                        "0200ed01"
+                    )
+                );
+
+                let val = Transaction::V1(TransactionV1::new(0, Vec::new(), Vec::new()));
+                let encoded_val = hex_encode(&val);
+                assert_eq!(encoded_val, "01000000");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_transaction_decoding() {
+    let val = Transaction::V1(TransactionV1::new(
+        123,
+        vec![TxInput::Utxo(UtxoOutPoint::new(
+            OutPointSourceId::BlockReward(Id::new(H256(from_hex(
+                "1122334455667788990011223344556677889900112233445566778899001122",
+            )))),
+            1,
+        ))],
+        vec![TxOutput::DataDeposit(from_hex("1234567890"))],
+    ));
+    let decoded_val = Transaction::decode_all(&mut val.encode().as_slice()).unwrap();
+    assert_eq!(decoded_val, val);
+
+    // Unknown versions are rejected.
+    for version in [0, 2, 0xff] {
+        let mut encoded_val = val.encode();
+        encoded_val[0] = version;
+        assert!(Transaction::decode_all(&mut encoded_val.as_slice()).is_err());
+    }
+}
+
+#[test]
+fn test_transaction_id() {
+    let test_vectors = [
+        (
+            concat!(
+                "01000400005a9c1e3b2f7d0e4c8a61b9d3f2e07c5a4b8d6e1f0a3c7b9e2d4f6a8c0e1b3d5f01000000",
+                "080000070010a5d4e8010c1d2e3f405162738495a6b7c8d9eafb0c1d2e3f00000b001e92898f2c01",
+                "f0e1d2c3b4a5968778695a4b3c2d1e0ff0e1d2c3"
+            ),
+            "222e0bce1a3db45db39d0757e7b34593aaa21671517fb796af80e30cff3717e3",
+        ),
+        (
+            concat!(
+                "010008000100000000a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c00000000",
+                "03007e57a11ce0ffee00deadbeef0badf00d7e57a11ce0ffee00deadbeef0badf00d07004429353a",
+                "0800023c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d214e0200",
+                "02a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f900100070045953617",
+                "01f0e1d2c3b4a5968778695a4b3c2d1e0ff0e1d2c3028170"
+            ),
+            "f44728220de3cf3f2058f0271b5a0a633d8fb465ee12fb12e365375545bee21e",
+        ),
+    ];
+
+    for (encoded_tx, expected_id) in test_vectors {
+        let encoded_tx: Vec<u8> = from_hex(encoded_tx);
+        let tx = Transaction::decode_all(&mut encoded_tx.as_slice()).unwrap();
+        assert_eq!(tx.encode(), encoded_tx);
+
+        let id = tx.get_id();
+        assert_eq!(id, TransactionId::new(H256(from_hex(expected_id))));
+    }
+}
+
+#[test]
+fn test_standard_input_signature_encoding() {
+    let val = StandardInputSignature {
+        sighash_type: SigHashType::all().with_anyone_can_pay(),
+        raw_signature: from_hex("1122334455"),
+    };
+    let encoded_val = hex_encode(&val);
+    assert_eq!(encoded_val, "81141122334455");
+}
+
+#[test]
+fn test_input_witness_encoding() {
+    for tag in InputWitnessTag::iter() {
+        match tag {
+            InputWitnessTag::NoSignature => {
+                let val = InputWitness::NoSignature(None);
+                let encoded_val = hex_encode(&val);
+                assert_eq!(encoded_val, "0000");
+
+                let val = InputWitness::NoSignature(Some(from_hex("1122334455")));
+                let encoded_val = hex_encode(&val);
+                assert_eq!(encoded_val, "0001141122334455");
+            }
+            InputWitnessTag::Standard => {
+                let val = InputWitness::Standard(StandardInputSignature {
+                    sighash_type: SigHashType::all(),
+                    raw_signature: from_hex("1122334455"),
+                });
+                let encoded_val = hex_encode(&val);
+                assert_eq!(encoded_val, "0101141122334455");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_signed_transaction_encoding() {
+    let tx = Transaction::V1(TransactionV1::new(
+        0,
+        vec![
+            TxInput::Utxo(UtxoOutPoint::new(
+                OutPointSourceId::Transaction(Id::new(H256(from_hex(
+                    "1122334455667788990011223344556677889900112233445566778899001122",
+                )))),
+                123,
+            )),
+            TxInput::Account(AccountOutPoint {
+                nonce: AccountNonce(123),
+                spending: AccountSpending::DelegationBalance(
+                    Id::new(H256(from_hex(
+                        "1122334455667788990011223344556677889900112233445566778899001122",
+                    ))),
+                    Amount::from_atoms(123),
+                ),
+            }),
+        ],
+        vec![TxOutput::Burn(OutputValue::Coin(Amount::from_atoms(123)))],
+    ));
+    let val = SignedTransaction::new(
+        tx.clone(),
+        vec![
+            InputWitness::NoSignature(None),
+            InputWitness::Standard(StandardInputSignature {
+                sighash_type: SigHashType::all(),
+                raw_signature: from_hex("1122334455"),
+            }),
+        ],
+    )
+    .unwrap();
+    let encoded_val = hex_encode(&val);
+    assert_eq!(
+        encoded_val,
+        concat!(
+            "010008",
+            "000011223344556677889900112233445566778899001122334455667788990011227b000000",
+            "01ed01001122334455667788990011223344556677889900112233445566778899001122ed01",
+            "040200ed01",
+            "08",
+            "0000",
+            "0101141122334455"
+        )
+    );
+    assert_eq!(val.get_id(), tx.get_id());
+
+    let decoded_val = SignedTransaction::decode_all(&mut val.encode().as_slice()).unwrap();
+    assert_eq!(decoded_val, val);
+}
+
+#[test]
+fn test_signed_transaction_witness_count() {
+    let tx = Transaction::V1(TransactionV1::new(
+        0,
+        vec![TxInput::Utxo(UtxoOutPoint::new(
+            OutPointSourceId::Transaction(Id::new(H256(from_hex(
+                "1122334455667788990011223344556677889900112233445566778899001122",
+            )))),
+            123,
+        ))],
+        vec![TxOutput::Burn(OutputValue::Coin(Amount::from_atoms(123)))],
+    ));
+
+    for witness_count in [0, 2] {
+        let signatures = vec![InputWitness::NoSignature(None); witness_count];
+
+        assert_eq!(
+            SignedTransaction::new(tx.clone(), signatures.clone()),
+            Err(SignedTransactionError::InvalidWitnessCount {
+                input_count: 1,
+                witness_count
+            })
+        );
+
+        let mut encoded_val = tx.encode();
+        signatures.encode_to(&mut encoded_val);
+        assert!(SignedTransaction::decode_all(&mut encoded_val.as_slice()).is_err());
+    }
+}
+
+#[test]
+fn test_sighash_type() {
+    for outputs_mode in OutputsMode::iter() {
+        for inputs_mode in InputsMode::iter() {
+            let val = SigHashType::new(outputs_mode, inputs_mode);
+            assert_eq!(val.outputs_mode(), outputs_mode);
+            assert_eq!(val.inputs_mode(), inputs_mode);
+            assert_eq!(SigHashType::try_from(val.get()), Ok(val));
+        }
+    }
+
+    assert_eq!(SigHashType::default(), SigHashType::all());
+    assert_eq!(SigHashType::all().get(), SigHashType::ALL);
+    assert_eq!(SigHashType::none().get(), SigHashType::NONE);
+    assert_eq!(SigHashType::single().get(), SigHashType::SINGLE);
+    assert_eq!(
+        SigHashType::single().with_anyone_can_pay().get(),
+        SigHashType::SINGLE | SigHashType::ANYONECANPAY
+    );
+
+    let val = SigHashType::try_from(0x83).unwrap();
+    assert_eq!(val.outputs_mode(), OutputsMode::Single);
+    assert_eq!(val.inputs_mode(), InputsMode::AnyoneCanPay);
+
+    let val = SigHashType::try_from(0x02).unwrap();
+    assert_eq!(val.outputs_mode(), OutputsMode::None);
+    assert_eq!(val.inputs_mode(), InputsMode::CommitWhoPays);
+
+    for invalid_val in [0x00, 0x04, 0x10, 0x41, 0x7f, 0x80, 0x84, 0xc1, 0xff] {
+        assert_eq!(
+            SigHashType::try_from(invalid_val),
+            Err(SigHashTypeError::InvalidValue(invalid_val))
+        );
+    }
+}
+
+#[test]
+fn test_sighash_type_encoding() {
+    let val = SigHashType::single().with_anyone_can_pay();
+    let encoded_val = hex_encode(&val);
+    assert_eq!(encoded_val, "83");
+
+    let decoded_val = SigHashType::decode_all(&mut from_hex::<Vec<u8>>("02").as_slice()).unwrap();
+    assert_eq!(decoded_val, SigHashType::none());
+
+    for invalid_val in ["00", "04", "80", "ff"] {
+        let encoded_val: Vec<u8> = from_hex(invalid_val);
+        assert!(SigHashType::decode_all(&mut encoded_val.as_slice()).is_err());
+    }
+}
+
+#[test]
+fn test_hashing() {
+    let mut hasher = DefaultHashAlgoStream::new();
+    hasher.update(b"");
+    assert_eq!(
+        hasher.finalize(),
+        H256(from_hex(
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        ))
+    );
+
+    let mut hasher = DefaultHashAlgoStream::new();
+    hasher.update(b"a");
+    hasher.update(b"bc");
+    assert_eq!(
+        hasher.finalize(),
+        H256(from_hex(
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        ))
+    );
+
+    // The encoded form of a string is prefixed with its compact-encoded length, i.e. 0x30 here.
+    let expected_hash = H256(from_hex(
+        "2ea73ea95ad1854fa58b381f46aad24efd813e9a936770417a1d8f55ce82d619",
+    ));
+    assert_eq!(hash_encoded("Hello, world"), expected_hash);
+
+    let mut hasher = DefaultHashAlgoStream::new();
+    hash_encoded_to("Hello, world", &mut hasher);
+    assert_eq!(hasher.finalize(), expected_hash);
+
+    let mut hasher = DefaultHashAlgoStream::new();
+    hasher.update(&[0x30]);
+    b"Hello, world".iter().for_each(|byte| hasher.update(&[*byte]));
+    assert_eq!(hasher.finalize(), expected_hash);
+}
+
+#[test]
+fn test_public_key_hash_from_public_key() {
+    let test_vectors = [
+        (
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "8603b50fdb239d6c4017500c85002ac28eae680b",
+        ),
+        (
+            "02a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90",
+            "c2e50395c30aa4b57f80f1a267bce61ae37057d0",
+        ),
+    ];
+
+    for (public_key, expected_hash) in test_vectors {
+        let public_key = PublicKey::Secp256k1Schnorr(Secp256k1PublicKey(from_hex(public_key)));
+        let expected_hash = PublicKeyHash(from_hex(expected_hash));
+
+        assert_eq!(PublicKeyHash::from_public_key(&public_key), expected_hash);
+        assert_eq!(PublicKeyHash::from(&public_key), expected_hash);
+    }
+}
+
+#[test]
+fn test_chain_config() {
+    for chain_type in ChainType::iter() {
+        let config = ChainConfig::new(chain_type);
+        assert_eq!(config.chain_type, chain_type);
+        assert_eq!(config.coin_decimals, 11);
+        assert_eq!(
+            config.min_stake_pool_pledge,
+            Amount::from_atoms(4_000_000_000_000_000)
+        );
+
+        let (expected_ticker, expected_coin_type) = match chain_type {
+            ChainType::Mainnet => ("ML", 19788),
+            ChainType::Testnet => ("TML", 1),
+            ChainType::Regtest => ("RML", 1),
+            ChainType::Signet => ("SML", 1),
+        };
+        assert_eq!(config.coin_ticker, expected_ticker);
+        assert_eq!(config.bip44_coin_type, expected_coin_type);
+    }
+
+    assert_eq!(ATOMS_PER_COIN, 100_000_000_000);
+}
@@ -0,0 +1,386 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn test_calculate_fill_order_v1() {
+    let fill = |asked, given, fill_amount| {
+        calculate_fill_order_v1(amount(asked), amount(given), amount(fill_amount))
+            .map(|amount| amount.into_atoms())
+    };
+
+    // Ask for 1 coin, give 20000 token atoms.
+    assert_eq!(fill(100_000_000_000, 20_000, 0), Some(0));
+    assert_eq!(fill(100_000_000_000, 20_000, 25_000_000_000), Some(5_000));
+    assert_eq!(fill(100_000_000_000, 20_000, 100_000_000_000), Some(20_000));
+    // Rounding down
+    assert_eq!(fill(100_000_000_000, 20_000, 4_999_999), Some(0));
+    assert_eq!(fill(100_000_000_000, 20_000, 5_000_000), Some(1));
+    assert_eq!(fill(3, 10, 1), Some(3));
+    assert_eq!(fill(3, 10, 2), Some(6));
+
+    // The intermediate product doesn't overflow.
+    assert_eq!(
+        fill(u128::MAX, u128::MAX, u128::MAX - 1),
+        Some(u128::MAX - 1)
+    );
+    assert_eq!(fill(u128::MAX, 1000, u128::MAX / 2), Some(499));
+
+    // Zero ask
+    assert_eq!(fill(0, 20_000, 0), None);
+    assert_eq!(fill(0, 20_000, 1), None);
+    // The result doesn't fit into Amount.
+    assert_eq!(fill(1, u128::MAX, 2), None);
+}
+
+#[test]
+fn test_calculate_fill_order_v1_value() {
+    let token_id = repeated_byte_id(0x01);
+
+    assert_eq!(
+        calculate_fill_order_v1_value(
+            &OutputValue::Coin(amount(100_000_000_000)),
+            &OutputValue::TokenV1(token_id, amount(20_000)),
+            amount(25_000_000_000),
+        ),
+        Some(OutputValue::TokenV1(token_id, amount(5_000)))
+    );
+    assert_eq!(
+        calculate_fill_order_v1_value(
+            &OutputValue::TokenV1(token_id, amount(20_000)),
+            &OutputValue::Coin(amount(100_000_000_000)),
+            amount(5_000),
+        ),
+        Some(OutputValue::Coin(amount(25_000_000_000)))
+    );
+    assert_eq!(
+        calculate_fill_order_v1_value(
+            &OutputValue::TokenV1(token_id, Amount::ZERO),
+            &OutputValue::Coin(amount(100_000_000_000)),
+            amount(5_000),
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_calculate_fill_order_v0() {
+    let fill = |ask_balance, give_balance, fill_amount| {
+        calculate_fill_order_v0(
+            amount(ask_balance),
+            amount(give_balance),
+            amount(fill_amount),
+        )
+        .map(|amount| amount.into_atoms())
+    };
+
+    assert_eq!(fill(100, 20_000, 0), Some(0));
+    assert_eq!(fill(100, 20_000, 25), Some(5_000));
+    assert_eq!(fill(100, 20_000, 100), Some(20_000));
+    // Rounding down
+    assert_eq!(fill(3, 10, 1), Some(3));
+    assert_eq!(fill(3, 10, 2), Some(6));
+    assert_eq!(fill(3, 10, 3), Some(10));
+
+    // The current balances define the price, e.g. after a fill of 25 from the example above,
+    // the price stays the same, but after a rounded fill it may change.
+    assert_eq!(fill(75, 15_000, 75), Some(15_000));
+    assert_eq!(fill(2, 7, 1), Some(3));
+
+    // The intermediate product doesn't overflow.
+    assert_eq!(
+        fill(u128::MAX, u128::MAX, u128::MAX - 1),
+        Some(u128::MAX - 1)
+    );
+
+    // Overbid
+    assert_eq!(fill(100, 20_000, 101), None);
+    assert_eq!(fill(0, 20_000, 1), None);
+    // Zero ask balance
+    assert_eq!(fill(0, 20_000, 0), None);
+}
+
+#[test]
+fn test_calculate_conclude_order_payout() {
+    assert_eq!(
+        calculate_conclude_order_payout(amount(100), amount(100), amount(20_000)),
+        Some(ConcludeOrderPayout {
+            filled_ask_amount: Amount::ZERO,
+            remaining_give_amount: amount(20_000),
+        })
+    );
+    assert_eq!(
+        calculate_conclude_order_payout(amount(100), amount(75), amount(15_000)),
+        Some(ConcludeOrderPayout {
+            filled_ask_amount: amount(25),
+            remaining_give_amount: amount(15_000),
+        })
+    );
+    assert_eq!(
+        calculate_conclude_order_payout(amount(100), Amount::ZERO, Amount::ZERO),
+        Some(ConcludeOrderPayout {
+            filled_ask_amount: amount(100),
+            remaining_give_amount: Amount::ZERO,
+        })
+    );
+    assert_eq!(
+        calculate_conclude_order_payout(amount(100), amount(101), amount(15_000)),
+        None
+    );
+}
+
+#[test]
+fn test_calculate_conclude_order_values() {
+    let token_id = repeated_byte_id(0x01);
+
+    assert_eq!(
+        calculate_conclude_order_values(
+            &OutputValue::Coin(amount(100)),
+            &OutputValue::TokenV1(token_id, amount(20_000)),
+            amount(75),
+            amount(15_000),
+        ),
+        Some((
+            OutputValue::Coin(amount(25)),
+            OutputValue::TokenV1(token_id, amount(15_000))
+        ))
+    );
+    assert_eq!(
+        calculate_conclude_order_values(
+            &OutputValue::TokenV1(token_id, amount(20_000)),
+            &OutputValue::Coin(amount(100)),
+            Amount::ZERO,
+            Amount::ZERO,
+        ),
+        Some((
+            OutputValue::TokenV1(token_id, amount(20_000)),
+            OutputValue::Coin(Amount::ZERO)
+        ))
+    );
+    assert_eq!(
+        calculate_conclude_order_values(
+            &OutputValue::Coin(amount(100)),
+            &OutputValue::TokenV1(token_id, amount(20_000)),
+            amount(101),
+            amount(15_000),
+        ),
+        None
+    );
+}
+
+fn make_order(ask: OutputValue, give: OutputValue) -> OrderData {
+    OrderData {
+        conclude_key: Destination::AnyoneCanSpend,
+        ask,
+        give,
+    }
+}
+
+#[test]
+fn test_order_data_validation() {
+    let token1 = repeated_byte_id(0x01);
+    let token2 = repeated_byte_id(0x02);
+
+    let order = make_order(
+        OutputValue::Coin(amount(100)),
+        OutputValue::TokenV1(token1, amount(5)),
+    );
+    assert_eq!(
+        order.currency_pair(),
+        (Currency::Coin, Currency::Token(token1))
+    );
+    assert_eq!(order.validate(), Ok(()));
+
+    let order = make_order(
+        OutputValue::TokenV1(token2, amount(100)),
+        OutputValue::TokenV1(token1, amount(5)),
+    );
+    assert_eq!(
+        order.currency_pair(),
+        (Currency::Token(token2), Currency::Token(token1))
+    );
+    assert_eq!(order.validate(), Ok(()));
+
+    let order = make_order(
+        OutputValue::TokenV1(token1, amount(100)),
+        OutputValue::TokenV1(token1, amount(5)),
+    );
+    assert_eq!(order.validate(), Err(OrderDataError::SameCurrencies));
+
+    let order = make_order(OutputValue::Coin(amount(100)), OutputValue::Coin(amount(5)));
+    assert_eq!(order.validate(), Err(OrderDataError::SameCurrencies));
+
+    let order = make_order(
+        OutputValue::Coin(Amount::ZERO),
+        OutputValue::TokenV1(token1, amount(5)),
+    );
+    assert_eq!(order.validate(), Err(OrderDataError::ZeroAskAmount));
+
+    let order = make_order(
+        OutputValue::Coin(amount(100)),
+        OutputValue::TokenV1(token1, Amount::ZERO),
+    );
+    assert_eq!(order.validate(), Err(OrderDataError::ZeroGiveAmount));
+}
+
+#[test]
+fn test_order_price() {
+    let token_id = repeated_byte_id(0x01);
+
+    let price = |numerator, denominator| {
+        OrderPrice::new(amount(numerator), amount(denominator))
+            .map(|price| (price.numerator(), price.denominator()))
+    };
+    assert_eq!(price(6, 4), Some((3, 2)));
+    assert_eq!(price(4, 6), Some((2, 3)));
+    assert_eq!(price(7, 1), Some((7, 1)));
+    assert_eq!(price(0, 5), Some((0, 1)));
+    assert_eq!(price(u128::MAX, u128::MAX), Some((1, 1)));
+    assert_eq!(price(u128::MAX, 5), Some((u128::MAX / 5, 1)));
+    assert_eq!(price(5, 0), None);
+    assert_eq!(price(0, 0), None);
+
+    // Ask for 25 coins (11 decimals), give 100 tokens (2 decimals).
+    let order = make_order(
+        OutputValue::Coin(amount(25 * ATOMS_PER_COIN)),
+        OutputValue::TokenV1(token_id, amount(10_000)),
+    );
+    let ask_per_give = order.ask_per_give().unwrap();
+    assert_eq!(
+        (ask_per_give.numerator(), ask_per_give.denominator()),
+        (250_000_000, 1)
+    );
+    let give_per_ask = order.give_per_ask().unwrap();
+    assert_eq!(
+        (give_per_ask.numerator(), give_per_ask.denominator()),
+        (1, 250_000_000)
+    );
+
+    let mut buf = [0u8; 64];
+    // 1 token = 0.25 coins
+    assert_eq!(
+        ask_per_give.into_fixedpoint_str(COIN_DECIMALS, 2, &mut buf),
+        Some("0.25")
+    );
+    // 1 coin = 4 tokens
+    assert_eq!(
+        give_per_ask.into_fixedpoint_str(2, COIN_DECIMALS, &mut buf),
+        Some("4")
+    );
+
+    // Prices are rounded down: ask for 1 token atom (2 decimals), give 3 coins.
+    let order = make_order(
+        OutputValue::TokenV1(token_id, amount(1)),
+        OutputValue::Coin(amount(3 * ATOMS_PER_COIN)),
+    );
+    // 1 coin = 0.003333... tokens
+    assert_eq!(
+        order.ask_per_give().unwrap().into_fixedpoint_str(2, COIN_DECIMALS, &mut buf),
+        Some("0")
+    );
+    // 1 token = 300 coins
+    assert_eq!(
+        order.give_per_ask().unwrap().into_fixedpoint_str(COIN_DECIMALS, 2, &mut buf),
+        Some("300")
+    );
+
+    // Ask for 10 tokens, give 3 coins: 1 coin = 3.333... tokens, 1 token = 0.3 coins.
+    let order = make_order(
+        OutputValue::TokenV1(token_id, amount(1_000)),
+        OutputValue::Coin(amount(3 * ATOMS_PER_COIN)),
+    );
+    assert_eq!(
+        order.ask_per_give().unwrap().into_fixedpoint_str(2, COIN_DECIMALS, &mut buf),
+        Some("3.33")
+    );
+    assert_eq!(
+        order.give_per_ask().unwrap().into_fixedpoint_str(COIN_DECIMALS, 2, &mut buf),
+        Some("0.3")
+    );
+
+    // Overflow of the unit price and the small buffer
+    let price = OrderPrice::new(Amount::MAX, amount(1)).unwrap();
+    assert_eq!(
+        price.into_fixedpoint_str(0, 0, &mut buf),
+        Some("340282366920938463463374607431768211455")
+    );
+    assert_eq!(price.into_fixedpoint_str(0, 1, &mut buf), None);
+    assert_eq!(price.into_fixedpoint_str(0, 0, &mut [0u8; 38]), None);
+    let price = OrderPrice::new(amount(1), amount(1)).unwrap();
+    assert_eq!(
+        price.into_fixedpoint_str(0, 38, &mut buf),
+        Some("100000000000000000000000000000000000000")
+    );
+    assert_eq!(price.into_fixedpoint_str(0, 39, &mut buf), None);
+
+    let order = make_order(
+        OutputValue::Coin(Amount::ZERO),
+        OutputValue::Coin(Amount::ZERO),
+    );
+    assert_eq!(order.ask_per_give(), None);
+    assert_eq!(order.give_per_ask(), None);
+}
+
+#[test]
+fn test_order_remaining_amounts() {
+    let token_id = repeated_byte_id(0x01);
+
+    // Ask for 10 coin atoms, give 3 token atoms.
+    let order = make_order(
+        OutputValue::Coin(amount(10)),
+        OutputValue::TokenV1(token_id, amount(3)),
+    );
+    let max_fill = |ask_balance, give_balance| {
+        order.max_fill_v1(amount(ask_balance), amount(give_balance)).map(|fill| {
+            (
+                fill.fill_amount.into_atoms(),
+                fill.filled_amount.into_atoms(),
+                fill.remaining_give_amount.into_atoms(),
+            )
+        })
+    };
+
+    // Not filled yet
+    assert_eq!(order.filled_ask_amount(amount(10)), Some(Amount::ZERO));
+    assert_eq!(order.filled_give_amount(amount(3)), Some(Amount::ZERO));
+    assert_eq!(max_fill(10, 3), Some((10, 3, 0)));
+
+    // After filling with 1 atom, which gives nothing due to rounding down
+    assert_eq!(order.filled_ask_amount(amount(9)), Some(amount(1)));
+    assert_eq!(order.filled_give_amount(amount(3)), Some(Amount::ZERO));
+    assert_eq!(max_fill(9, 3), Some((9, 2, 1)));
+
+    // After filling with 5 atoms, which gives 1 atom
+    assert_eq!(order.filled_ask_amount(amount(5)), Some(amount(5)));
+    assert_eq!(order.filled_give_amount(amount(2)), Some(amount(1)));
+    assert_eq!(max_fill(5, 2), Some((5, 1, 1)));
+
+    // Completely filled
+    assert_eq!(order.filled_ask_amount(Amount::ZERO), Some(amount(10)));
+    assert_eq!(max_fill(0, 1), Some((0, 0, 1)));
+
+    // Balances that can't belong to the order
+    assert_eq!(order.filled_ask_amount(amount(11)), None);
+    assert_eq!(order.filled_give_amount(amount(4)), None);
+    assert_eq!(max_fill(11, 3), None);
+    assert_eq!(max_fill(9, 1), None);
+
+    let order = make_order(
+        OutputValue::Coin(Amount::ZERO),
+        OutputValue::TokenV1(token_id, amount(3)),
+    );
+    assert_eq!(order.max_fill_v1(Amount::ZERO, amount(3)), None);
+}
@@ -0,0 +1,286 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn make_test_tx() -> (Transaction, Vec<SighashInputCommitment>) {
+    let order_id = Id::new(H256(from_hex(
+        "7e57a11ce0ffee00deadbeef0badf00d7e57a11ce0ffee00deadbeef0badf00d",
+    )));
+    let token_id = Id::new(H256(from_hex(
+        "3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d",
+    )));
+    let pkh = PublicKeyHash(from_hex("f0e1d2c3b4a5968778695a4b3c2d1e0ff0e1d2c3"));
+
+    let tx = Transaction::V1(TransactionV1::new(
+        0,
+        vec![
+            TxInput::Utxo(UtxoOutPoint::new(
+                OutPointSourceId::Transaction(Id::new(H256(from_hex(
+                    "5a9c1e3b2f7d0e4c8a61b9d3f2e07c5a4b8d6e1f0a3c7b9e2d4f6a8c0e1b3d5f",
+                )))),
+                1,
+            )),
+            TxInput::OrderAccountCommand(OrderAccountCommand::FillOrder(
+                order_id,
+                Amount::from_atoms(250_000_000_000),
+            )),
+            TxInput::Account(AccountOutPoint {
+                nonce: AccountNonce(5),
+                spending: AccountSpending::DelegationBalance(
+                    Id::new(H256(from_hex(
+                        "00000000a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c",
+                    ))),
+                    Amount::from_atoms(1_000_000_000_000),
+                ),
+            }),
+        ],
+        vec![
+            TxOutput::Transfer(
+                OutputValue::TokenV1(token_id, Amount::from_atoms(5_000)),
+                Destination::PublicKeyHash(pkh),
+            ),
+            TxOutput::Transfer(
+                OutputValue::Coin(Amount::from_atoms(48_995_000_000_000)),
+                Destination::PublicKeyHash(pkh),
+            ),
+        ],
+    ));
+    let input_commitments = vec![
+        SighashInputCommitment::Utxo(TxOutput::Transfer(
+            OutputValue::Coin(Amount::from_atoms(49_000_000_000_000)),
+            Destination::PublicKeyHash(pkh),
+        )),
+        SighashInputCommitment::FillOrderAccountCommand {
+            initially_asked: OutputValue::Coin(Amount::from_atoms(1_000_000_000_000)),
+            initially_given: OutputValue::TokenV1(token_id, Amount::from_atoms(20_000)),
+        },
+        SighashInputCommitment::None,
+    ];
+
+    (tx, input_commitments)
+}
+
+#[test]
+fn test_signature_hash() {
+    let (tx, input_commitments) = make_test_tx();
+
+    let all_hash = "d269aae08f3c3f4a1e1a40f539bb1d21a4fcd77f1aea2996e4835364b9aa412c";
+    let none_hash = "d461639cf09f304dc4ff0fefd289a90225e22c8a03af96410c752b2a59c975a1";
+    let test_vectors = [
+        (SigHashType::all(), [all_hash, all_hash, all_hash]),
+        (SigHashType::none(), [none_hash, none_hash, none_hash]),
+        (
+            SigHashType::all().with_anyone_can_pay(),
+            [
+                "022cbc72f2f4c9f7da924c880ea2e8118ff0b07dbfc71b511160e7e50fb707b8",
+                "53e99f425e40d7d6c25c73d2182e03c262981acddd9e72e2a348b418b936fdec",
+                "dfa7cfd12bf42278c1ab1ee27981095932693958e8b29a2340bde3ae3f84f32d",
+            ],
+        ),
+        (
+            SigHashType::none().with_anyone_can_pay(),
+            [
+                "1f90b78322f6adf190b5394cb827dda2d828ee0bcce2956c857fc4076ed69775",
+                "dad71d437eb165539b0d618c763d5a9e2854903f2e77f5d33e177eb0baa6cd2a",
+                "687d3aefa626d2fb601c85132d4ab832a0264d1607c1eb3d4fd0845359d5bd9b",
+            ],
+        ),
+    ];
+
+    for (sighash_type, expected_hashes) in test_vectors {
+        for (input_index, expected_hash) in expected_hashes.into_iter().enumerate() {
+            let hash = signature_hash(&tx, &input_commitments, input_index, sighash_type);
+            assert_eq!(hash, Ok(H256(from_hex(expected_hash))));
+        }
+    }
+
+    // In SINGLE mode, the input with index 2 has no corresponding output.
+    let test_vectors = [
+        (
+            SigHashType::single(),
+            [
+                "68775c5eb8d065e981dd7d32cc7500a3be3dc479dc7b9286e328b0dd4447c709",
+                "6e589e3ddebb3e7b28b52429d6979c298a6c99a8871b82f28a393816200ad560",
+            ],
+        ),
+        (
+            SigHashType::single().with_anyone_can_pay(),
+            [
+                "7451fb9623515bfd21ccf8bc60bc8300f68df1ad4ecc15aed4e32870d4805826",
+                "14f005f5eb0e7c1ba47c2b5363c649fd4f3035a9db8b2467988d702e1918a34e",
+            ],
+        ),
+    ];
+
+    for (sighash_type, expected_hashes) in test_vectors {
+        for (input_index, expected_hash) in expected_hashes.into_iter().enumerate() {
+            let hash = signature_hash(&tx, &input_commitments, input_index, sighash_type);
+            assert_eq!(hash, Ok(H256(from_hex(expected_hash))));
+        }
+
+        assert_eq!(
+            signature_hash(&tx, &input_commitments, 2, sighash_type),
+            Err(SighashError::NoOutputForSingleMode {
+                input_index: 2,
+                output_count: 2
+            })
+        );
+    }
+}
+
+#[test]
+fn test_signature_hash_errors() {
+    let (tx, input_commitments) = make_test_tx();
+
+    assert_eq!(
+        signature_hash(&tx, &input_commitments, 3, SigHashType::all()),
+        Err(SighashError::InvalidInputIndex {
+            input_index: 3,
+            input_count: 3
+        })
+    );
+
+    assert_eq!(
+        signature_hash(&tx, &input_commitments[..2], 0, SigHashType::all()),
+        Err(SighashError::InvalidInputCommitmentCount {
+            input_count: 3,
+            commitment_count: 2
+        })
+    );
+
+    let mut extra_commitments = input_commitments.clone();
+    extra_commitments.push(SighashInputCommitment::None);
+    assert_eq!(
+        signature_hash(&tx, &extra_commitments, 0, SigHashType::none()),
+        Err(SighashError::InvalidInputCommitmentCount {
+            input_count: 3,
+            commitment_count: 4
+        })
+    );
+}
+
+fn stream_signature_hash(
+    tx: &Transaction,
+    input_commitments: &[SighashInputCommitment],
+    input_index: usize,
+    sighash_type: SigHashType,
+) -> Result<H256, SighashError> {
+    let mut stream = SighashStream::new(
+        sighash_type,
+        input_index,
+        tx.flags(),
+        tx.inputs().len(),
+        tx.outputs().len(),
+    )?;
+
+    for input in tx.inputs() {
+        stream.add_input(input)?;
+    }
+    for commitment in input_commitments {
+        stream.add_input_commitment(commitment)?;
+    }
+    for output in tx.outputs() {
+        stream.add_output(output)?;
+    }
+
+    stream.finalize()
+}
+
+#[test]
+fn test_sighash_stream_matches_signature_hash() {
+    let (tx, input_commitments) = make_test_tx();
+
+    let tx_without_outputs = Transaction::V1(TransactionV1::new(
+        tx.flags(),
+        tx.inputs().to_vec(),
+        Vec::new(),
+    ));
+
+    for tx in [tx, tx_without_outputs] {
+        for outputs_mode in OutputsMode::iter() {
+            for inputs_mode in InputsMode::iter() {
+                let sighash_type = SigHashType::new(outputs_mode, inputs_mode);
+
+                for input_index in 0..tx.inputs().len() + 1 {
+                    let expected_hash =
+                        signature_hash(&tx, &input_commitments, input_index, sighash_type);
+                    let hash =
+                        stream_signature_hash(&tx, &input_commitments, input_index, sighash_type);
+                    assert_eq!(hash, expected_hash);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sighash_stream_order() {
+    let (tx, input_commitments) = make_test_tx();
+    let new_stream = || SighashStream::new(SigHashType::all(), 0, 0, 3, 2).unwrap();
+
+    // A commitment before all inputs have been passed.
+    let mut stream = new_stream();
+    stream.add_input(&tx.inputs()[0]).unwrap();
+    assert_eq!(
+        stream.add_input_commitment(&input_commitments[0]),
+        Err(SighashError::UnexpectedInputCommitment)
+    );
+
+    // An output before all commitments have been passed.
+    let mut stream = new_stream();
+    for input in tx.inputs() {
+        stream.add_input(input).unwrap();
+    }
+    assert_eq!(
+        stream.add_output(&tx.outputs()[0]),
+        Err(SighashError::UnexpectedOutput)
+    );
+
+    // Too many elements.
+    let mut stream = new_stream();
+    for input in tx.inputs() {
+        stream.add_input(input).unwrap();
+    }
+    assert_eq!(
+        stream.add_input(&tx.inputs()[0]),
+        Err(SighashError::UnexpectedInput)
+    );
+    for commitment in &input_commitments {
+        stream.add_input_commitment(commitment).unwrap();
+    }
+    assert_eq!(
+        stream.add_input_commitment(&input_commitments[0]),
+        Err(SighashError::UnexpectedInputCommitment)
+    );
+    for output in tx.outputs() {
+        stream.add_output(output).unwrap();
+    }
+    assert_eq!(
+        stream.add_output(&tx.outputs()[0]),
+        Err(SighashError::UnexpectedOutput)
+    );
+
+    // Not enough elements.
+    let mut stream = new_stream();
+    for input in tx.inputs() {
+        stream.add_input(input).unwrap();
+    }
+    for commitment in &input_commitments {
+        stream.add_input_commitment(commitment).unwrap();
+    }
+    stream.add_output(&tx.outputs()[0]).unwrap();
+    assert_eq!(stream.finalize(), Err(SighashError::IncompleteStream));
+}
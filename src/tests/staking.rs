@@ -0,0 +1,177 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn make_pool_data(margin_ratio_per_thousand: u16, cost_per_block: AmountUIntType) -> StakePoolData {
+    let pkh = PublicKeyHash::repeat_byte(0x11);
+
+    StakePoolData {
+        pledge: amount(40_000 * ATOMS_PER_COIN),
+        staker: Destination::PublicKeyHash(pkh),
+        vrf_public_key: VrfPublicKey::Schnorrkel(SchnorrkelPublicKey([0x22; 32])),
+        decommission_key: Destination::PublicKeyHash(pkh),
+        margin_ratio_per_thousand: PerThousand::new(margin_ratio_per_thousand).unwrap(),
+        cost_per_block: amount(cost_per_block),
+    }
+}
+
+fn distribute(
+    pool_data: &StakePoolData,
+    total_reward: AmountUIntType,
+    staker_balance: AmountUIntType,
+    delegations: &[(u8, AmountUIntType)],
+) -> (AmountUIntType, Vec<(u8, AmountUIntType)>) {
+    let delegations = delegations
+        .iter()
+        .map(|(id, balance)| (repeated_byte_id(*id), amount(*balance)))
+        .collect::<Vec<_>>();
+    let distribution = pool_data
+        .distribute_reward(amount(total_reward), amount(staker_balance), &delegations)
+        .unwrap();
+
+    let delegation_rewards = distribution
+        .delegation_rewards
+        .iter()
+        .map(|(id, reward)| (id.hash().0[0], reward.into_atoms()))
+        .collect();
+
+    (distribution.staker_reward.into_atoms(), delegation_rewards)
+}
+
+#[test]
+fn test_distribute_reward() {
+    // Reward 1000, cost 100, margin 10% of 900 = 90; the remaining 810 is split according to
+    // the balances: 600 for the staker, 400 for the delegations.
+    let pool_data = make_pool_data(100, 100);
+    assert_eq!(
+        distribute(&pool_data, 1000, 600, &[(1, 300), (2, 100)]),
+        (676, vec![(1, 243), (2, 81)])
+    );
+
+    // The same with rounding: margin 90.1 -> 90, delegations' part 324.4 -> 324.
+    assert_eq!(
+        distribute(&pool_data, 1001, 600, &[(1, 300), (2, 100)]),
+        (677, vec![(1, 243), (2, 81)])
+    );
+
+    // The dust of the per-delegation split goes to the staker.
+    let pool_data = make_pool_data(0, 0);
+    assert_eq!(
+        distribute(&pool_data, 10, 0, &[(1, 1), (2, 1), (3, 1)]),
+        (1, vec![(1, 3), (2, 3), (3, 3)])
+    );
+
+    // The reward doesn't cover the cost per block.
+    let pool_data = make_pool_data(100, 100);
+    assert_eq!(
+        distribute(&pool_data, 50, 600, &[(1, 300), (2, 100)]),
+        (50, vec![(1, 0), (2, 0)])
+    );
+
+    // No delegations or no delegated balance
+    assert_eq!(distribute(&pool_data, 1000, 600, &[]), (1000, vec![]));
+    assert_eq!(
+        distribute(&pool_data, 1000, 600, &[(1, 0)]),
+        (1000, vec![(1, 0)])
+    );
+
+    // 100% margin
+    let pool_data = make_pool_data(1000, 100);
+    assert_eq!(
+        distribute(&pool_data, 1000, 600, &[(1, 300), (2, 100)]),
+        (1000, vec![(1, 0), (2, 0)])
+    );
+
+    // Big values don't overflow the intermediate products.
+    let pool_data = make_pool_data(0, 0);
+    assert_eq!(
+        distribute(
+            &pool_data,
+            u128::MAX,
+            0,
+            &[(1, u128::MAX / 2), (2, u128::MAX / 2)]
+        ),
+        (1, vec![(1, u128::MAX / 2), (2, u128::MAX / 2)])
+    );
+}
+
+#[test]
+fn test_distribute_reward_overflow() {
+    let pool_data = make_pool_data(100, 100);
+
+    assert_eq!(
+        pool_data.distribute_reward(
+            amount(1000),
+            amount(600),
+            &[(repeated_byte_id(1), Amount::MAX), (repeated_byte_id(2), amount(1))]
+        ),
+        Err(RewardDistributionError::Overflow)
+    );
+    assert_eq!(
+        pool_data.distribute_reward(
+            amount(1000),
+            amount(1),
+            &[(repeated_byte_id(1), Amount::MAX)]
+        ),
+        Err(RewardDistributionError::Overflow)
+    );
+}
+
+#[test]
+fn test_stake_pool_data_validation() {
+    let chain_config = ChainConfig::new(ChainType::Mainnet);
+    let public_key = PublicKey::Secp256k1Schnorr(Secp256k1PublicKey([0x02; 33]));
+
+    let mut pool_data = make_pool_data(100, 100);
+    pool_data.staker = Destination::PublicKey(public_key.clone());
+    assert_eq!(pool_data.validate(&chain_config), Ok(()));
+
+    // Pledge
+    pool_data.pledge = chain_config.min_stake_pool_pledge;
+    assert_eq!(pool_data.validate(&chain_config), Ok(()));
+    pool_data.pledge = Amount::MAX;
+    assert_eq!(pool_data.validate(&chain_config), Ok(()));
+    pool_data.pledge = amount(40_000 * ATOMS_PER_COIN - 1);
+    assert_eq!(
+        pool_data.validate(&chain_config),
+        Err(StakePoolDataError::PledgeTooLow {
+            pledge: amount(40_000 * ATOMS_PER_COIN - 1),
+            min_pledge: amount(40_000 * ATOMS_PER_COIN),
+        })
+    );
+    pool_data.pledge = chain_config.min_stake_pool_pledge;
+
+    // Staker
+    for staker in [
+        Destination::AnyoneCanSpend,
+        Destination::PublicKeyHash(PublicKeyHash::from_public_key(&public_key)),
+        Destination::ScriptHash(repeated_byte_id(0x33)),
+        Destination::ClassicMultisig(PublicKeyHash::repeat_byte(0x44)),
+    ] {
+        pool_data.staker = staker;
+        assert_eq!(
+            pool_data.validate(&chain_config),
+            Err(StakePoolDataError::StakerNotPublicKey)
+        );
+    }
+    pool_data.staker = Destination::PublicKey(public_key);
+
+    // Any decommission key, margin and cost per block are fine.
+    pool_data.decommission_key = Destination::AnyoneCanSpend;
+    pool_data.margin_ratio_per_thousand = PerThousand::new(1000).unwrap();
+    pool_data.cost_per_block = Amount::MAX;
+    assert_eq!(pool_data.validate(&chain_config), Ok(()));
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// The output was created at height 100 and time 1000; the tip is at height 110 and
+// the median time is 5000.
+fn evaluate(lock: OutputTimeLock) -> Result<TimelockState, TimelockError> {
+    lock.evaluate(
+        BlockHeight(100),
+        BlockTimestamp::from_int_seconds(1000),
+        BlockHeight(110),
+        BlockTimestamp::from_int_seconds(5000),
+    )
+}
+
+#[test]
+fn test_timelock_until_height() {
+    // The next block has height 111.
+    assert_eq!(
+        evaluate(OutputTimeLock::UntilHeight(BlockHeight(0))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::UntilHeight(BlockHeight(111))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::UntilHeight(BlockHeight(112))),
+        Ok(TimelockState::LockedUntilHeight(BlockHeight(112)))
+    );
+}
+
+#[test]
+fn test_timelock_until_time() {
+    assert_eq!(
+        evaluate(OutputTimeLock::UntilTime(BlockTimestamp::from_int_seconds(
+            4999
+        ))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::UntilTime(BlockTimestamp::from_int_seconds(
+            5000
+        ))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::UntilTime(BlockTimestamp::from_int_seconds(
+            5001
+        ))),
+        Ok(TimelockState::LockedUntilTime(
+            BlockTimestamp::from_int_seconds(5001)
+        ))
+    );
+}
+
+#[test]
+fn test_timelock_for_block_count() {
+    assert_eq!(
+        evaluate(OutputTimeLock::ForBlockCount(BlocksCount(0))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForBlockCount(BlocksCount(11))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForBlockCount(BlocksCount(12))),
+        Ok(TimelockState::LockedUntilHeight(BlockHeight(112)))
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForBlockCount(BlocksCount((1 << 63) - 1))),
+        Ok(TimelockState::LockedUntilHeight(BlockHeight(
+            (1 << 63) + 99
+        )))
+    );
+    // Counts that don't fit into a signed block distance
+    assert_eq!(
+        evaluate(OutputTimeLock::ForBlockCount(BlocksCount(1 << 63))),
+        Err(TimelockError::HeightOverflow)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForBlockCount(BlocksCount(u64::MAX - 100))),
+        Err(TimelockError::HeightOverflow)
+    );
+}
+
+#[test]
+fn test_timelock_for_seconds() {
+    assert_eq!(
+        evaluate(OutputTimeLock::ForSeconds(SecondsCount(0))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForSeconds(SecondsCount(4000))),
+        Ok(TimelockState::Unlocked)
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForSeconds(SecondsCount(4001))),
+        Ok(TimelockState::LockedUntilTime(
+            BlockTimestamp::from_int_seconds(5001)
+        ))
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForSeconds(SecondsCount(u64::MAX - 1000))),
+        Ok(TimelockState::LockedUntilTime(
+            BlockTimestamp::from_int_seconds(u64::MAX)
+        ))
+    );
+    assert_eq!(
+        evaluate(OutputTimeLock::ForSeconds(SecondsCount(u64::MAX - 999))),
+        Err(TimelockError::TimestampOverflow)
+    );
+}
+
+#[test]
+fn test_timelock_tip_height_overflow() {
+    assert_eq!(
+        OutputTimeLock::UntilHeight(BlockHeight(0)).evaluate(
+            BlockHeight(0),
+            BlockTimestamp::from_int_seconds(0),
+            BlockHeight(u64::MAX),
+            BlockTimestamp::from_int_seconds(0),
+        ),
+        Err(TimelockError::HeightOverflow)
+    );
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn make_token_issuance() -> TokenIssuanceV1 {
+    TokenIssuanceV1 {
+        token_ticker: b"TKN1".to_vec(),
+        number_of_decimals: 8,
+        metadata_uri: b"https://example.com/token.json?v=1#x".to_vec(),
+        total_supply: TokenTotalSupply::Fixed(Amount::from_atoms(1_000_000)),
+        authority: Destination::AnyoneCanSpend,
+        is_freezable: IsTokenFreezable::No,
+    }
+}
+
+#[test]
+fn test_token_issuance_validation() {
+    let chain_config = ChainConfig::new(ChainType::Mainnet);
+    let validate = |f: &dyn Fn(&mut TokenIssuanceV1)| {
+        let mut issuance = make_token_issuance();
+        f(&mut issuance);
+        issuance.validate(&chain_config)
+    };
+
+    assert_eq!(validate(&|_| {}), Ok(()));
+
+    // Ticker
+    assert_eq!(validate(&|i| i.token_ticker = b"A".to_vec()), Ok(()));
+    assert_eq!(
+        validate(&|i| i.token_ticker = b"abcDEF123456".to_vec()),
+        Ok(())
+    );
+    assert_eq!(
+        validate(&|i| i.token_ticker = Vec::new()),
+        Err(TokenIssuanceError::InvalidTickerLength(0))
+    );
+    assert_eq!(
+        validate(&|i| i.token_ticker = b"abcDEF1234567".to_vec()),
+        Err(TokenIssuanceError::InvalidTickerLength(13))
+    );
+    for ticker in [&b"TK N"[..], b"TK-N", b"TK_N", b"TK.N", "TKÑ".as_bytes(), b"TK\0"] {
+        assert_eq!(
+            validate(&|i| i.token_ticker = ticker.to_vec()),
+            Err(TokenIssuanceError::InvalidTickerCharset)
+        );
+    }
+
+    // Decimals
+    assert_eq!(validate(&|i| i.number_of_decimals = 0), Ok(()));
+    assert_eq!(validate(&|i| i.number_of_decimals = 18), Ok(()));
+    assert_eq!(
+        validate(&|i| i.number_of_decimals = 19),
+        Err(TokenIssuanceError::TooManyDecimals(19))
+    );
+
+    // Metadata URI
+    assert_eq!(validate(&|i| i.metadata_uri = Vec::new()), Ok(()));
+    assert_eq!(
+        validate(&|i| i.metadata_uri = b"ipfs://a-b_c.d~e/f;g=h&i=%20[j]@k!l$m'n(o)*p+q,r".to_vec()),
+        Ok(())
+    );
+    assert_eq!(validate(&|i| i.metadata_uri = vec![b'a'; 1024]), Ok(()));
+    assert_eq!(
+        validate(&|i| i.metadata_uri = vec![b'a'; 1025]),
+        Err(TokenIssuanceError::MetadataUriTooLong(1025))
+    );
+    for uri in [
+        &b"https://example.com/a b"[..],
+        b"https://example.com/<a>",
+        b"https://example.com/\"a\"",
+        b"https://example.com/a\\b",
+        b"https://example.com/{a}",
+        b"https://example.com/a|b",
+        b"https://example.com/a^b",
+        b"https://example.com/`a`",
+        b"https://example.com/\n",
+        "https://example.com/ä".as_bytes(),
+    ] {
+        assert_eq!(
+            validate(&|i| i.metadata_uri = uri.to_vec()),
+            Err(TokenIssuanceError::InvalidMetadataUriCharset)
+        );
+    }
+
+    // Total supply
+    assert_eq!(
+        validate(&|i| i.total_supply = TokenTotalSupply::Fixed(Amount::from_atoms(1))),
+        Ok(())
+    );
+    assert_eq!(
+        validate(&|i| i.total_supply = TokenTotalSupply::Lockable),
+        Ok(())
+    );
+    assert_eq!(
+        validate(&|i| i.total_supply = TokenTotalSupply::Unlimited),
+        Ok(())
+    );
+    assert_eq!(
+        validate(&|i| i.total_supply = TokenTotalSupply::Fixed(Amount::ZERO)),
+        Err(TokenIssuanceError::ZeroFixedSupply)
+    );
+}
+
+fn make_nft_issuance() -> NftIssuanceV0 {
+    NftIssuanceV0 {
+        creator: None,
+        name: b"MyNft01".to_vec(),
+        description: "A picture of a café".as_bytes().to_vec(),
+        ticker: b"NFT".to_vec(),
+        icon_uri: b"https://example.com/icon.png".to_vec(),
+        additional_metadata_uri: Vec::new(),
+        media_uri: b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec(),
+        media_hash: vec![0xab; 32],
+    }
+}
+
+#[test]
+fn test_nft_issuance_validation() {
+    let chain_config = ChainConfig::new(ChainType::Testnet);
+    let validate = |f: &dyn Fn(&mut NftIssuanceV0)| {
+        let mut issuance = make_nft_issuance();
+        f(&mut issuance);
+        issuance.validate(&chain_config)
+    };
+    let invalid_length = |field, len| Err(NftIssuanceError::InvalidLength { field, len });
+    let invalid_charset = |field| Err(NftIssuanceError::InvalidCharset { field });
+
+    assert_eq!(validate(&|_| {}), Ok(()));
+
+    // Name
+    assert_eq!(validate(&|i| i.name = b"a".to_vec()), Ok(()));
+    assert_eq!(validate(&|i| i.name = b"abcDE12345".to_vec()), Ok(()));
+    assert_eq!(
+        validate(&|i| i.name = Vec::new()),
+        invalid_length(NftIssuanceField::Name, 0)
+    );
+    assert_eq!(
+        validate(&|i| i.name = b"abcDE123456".to_vec()),
+        invalid_length(NftIssuanceField::Name, 11)
+    );
+    for name in [&b"My Nft"[..], b"My-Nft", b"My_Nft", "Nfté".as_bytes()] {
+        assert_eq!(
+            validate(&|i| i.name = name.to_vec()),
+            invalid_charset(NftIssuanceField::Name)
+        );
+    }
+
+    // Description
+    assert_eq!(validate(&|i| i.description = vec![b' '; 100]), Ok(()));
+    assert_eq!(
+        validate(&|i| i.description = Vec::new()),
+        invalid_length(NftIssuanceField::Description, 0)
+    );
+    assert_eq!(
+        validate(&|i| i.description = vec![b'a'; 101]),
+        invalid_length(NftIssuanceField::Description, 101)
+    );
+    assert_eq!(
+        validate(&|i| i.description = vec![0xff, 0xfe]),
+        invalid_charset(NftIssuanceField::Description)
+    );
+
+    // Ticker
+    assert_eq!(validate(&|i| i.ticker = b"abcDEF123456".to_vec()), Ok(()));
+    assert_eq!(
+        validate(&|i| i.ticker = Vec::new()),
+        invalid_length(NftIssuanceField::Ticker, 0)
+    );
+    assert_eq!(
+        validate(&|i| i.ticker = b"abcDEF1234567".to_vec()),
+        invalid_length(NftIssuanceField::Ticker, 13)
+    );
+    assert_eq!(
+        validate(&|i| i.ticker = b"NF T".to_vec()),
+        invalid_charset(NftIssuanceField::Ticker)
+    );
+
+    // URIs
+    let set_uri = |issuance: &mut NftIssuanceV0, field, uri: &[u8]| {
+        let target = match field {
+            NftIssuanceField::IconUri => &mut issuance.icon_uri,
+            NftIssuanceField::AdditionalMetadataUri => &mut issuance.additional_metadata_uri,
+            NftIssuanceField::MediaUri => &mut issuance.media_uri,
+            _ => unreachable!(),
+        };
+        *target = uri.to_vec();
+    };
+    for field in [
+        NftIssuanceField::IconUri,
+        NftIssuanceField::AdditionalMetadataUri,
+        NftIssuanceField::MediaUri,
+    ] {
+        assert_eq!(validate(&|i| set_uri(i, field, b"")), Ok(()));
+        assert_eq!(validate(&|i| set_uri(i, field, &[b'a'; 1024])), Ok(()));
+        assert_eq!(
+            validate(&|i| set_uri(i, field, &[b'a'; 1025])),
+            invalid_length(field, 1025)
+        );
+        assert_eq!(
+            validate(&|i| set_uri(i, field, b"https://example.com/a b")),
+            invalid_charset(field)
+        );
+        assert_eq!(
+            validate(&|i| set_uri(i, field, b"https://example.com/<a>")),
+            invalid_charset(field)
+        );
+    }
+
+    // Media hash
+    assert_eq!(validate(&|i| i.media_hash = vec![0; 4]), Ok(()));
+    assert_eq!(
+        validate(&|i| i.media_hash = Vec::new()),
+        invalid_length(NftIssuanceField::MediaHash, 0)
+    );
+    assert_eq!(
+        validate(&|i| i.media_hash = vec![0; 3]),
+        invalid_length(NftIssuanceField::MediaHash, 3)
+    );
+    assert_eq!(
+        validate(&|i| i.media_hash = vec![0; 33]),
+        invalid_length(NftIssuanceField::MediaHash, 33)
+    );
+}
@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::{Amount, AmountUIntType, H256, Id};
+
 // The value that a compact-encoded integer must have for its encoded form to be 2-bytes long,
 // 4-bytes long etc.
 pub const SCALE_CODEC_COMPACT_ENC_2_BYTE_VAL_START: u16 = 1 << 6;
@@ -30,3 +32,12 @@ pub const SCALE_CODEC_COMPACT_ENC_14_BYTE_VAL_START: u128 = 1 << 96;
 pub const SCALE_CODEC_COMPACT_ENC_15_BYTE_VAL_START: u128 = 1 << 104;
 pub const SCALE_CODEC_COMPACT_ENC_16_BYTE_VAL_START: u128 = 1 << 112;
 pub const SCALE_CODEC_COMPACT_ENC_17_BYTE_VAL_START: u128 = 1 << 120;
+
+pub fn amount(atoms: AmountUIntType) -> Amount {
+    Amount::from_atoms(atoms)
+}
+
+/// An id whose hash consists of the repeated byte.
+pub fn repeated_byte_id<Tag>(byte: u8) -> Id<Tag> {
+    Id::new(H256::repeat_byte(byte))
+}
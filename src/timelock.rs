@@ -0,0 +1,95 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{BlockHeight, BlockTimestamp, OutputTimeLock};
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum TimelockError {
+    #[error("Block height overflow")]
+    HeightOverflow,
+
+    #[error("Timestamp overflow")]
+    TimestampOverflow,
+}
+
+/// The result of the evaluation of a timelock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelockState {
+    /// The output can be spent in the next block.
+    Unlocked,
+
+    /// The output can't be spent in a block below the specified height.
+    LockedUntilHeight(BlockHeight),
+
+    /// The output can't be spent until the median time of the chain reaches the specified
+    /// timestamp.
+    LockedUntilTime(BlockTimestamp),
+}
+
+impl OutputTimeLock {
+    /// Determine whether an output with this timelock can be spent in the block following
+    /// the current tip.
+    ///
+    /// `source_height` and `source_time` are the height and the timestamp of the block
+    /// containing the output; `tip_height` and `median_time` describe the current tip of
+    /// the chain. Relative locks are counted from the source block; time-based locks are
+    /// compared against the median time, as Core does.
+    ///
+    /// An error is returned if the unlock height or time doesn't fit into its type, or if
+    /// a relative block count exceeds `i64::MAX`; such outputs can never be spent.
+    pub fn evaluate(
+        &self,
+        source_height: BlockHeight,
+        source_time: BlockTimestamp,
+        tip_height: BlockHeight,
+        median_time: BlockTimestamp,
+    ) -> Result<TimelockState, TimelockError> {
+        let spend_height = tip_height.next_height().ok_or(TimelockError::HeightOverflow)?;
+
+        match self {
+            OutputTimeLock::UntilHeight(height) => Ok(check_height(spend_height, *height)),
+            OutputTimeLock::UntilTime(time) => Ok(check_time(median_time, *time)),
+            OutputTimeLock::ForBlockCount(count) => {
+                // Core converts the count into a signed block distance, failing if it doesn't fit.
+                i64::try_from(count.0).map_err(|_| TimelockError::HeightOverflow)?;
+
+                let unlock_height =
+                    (source_height + *count).ok_or(TimelockError::HeightOverflow)?;
+                Ok(check_height(spend_height, unlock_height))
+            }
+            OutputTimeLock::ForSeconds(seconds) => {
+                let unlock_time =
+                    (source_time + *seconds).ok_or(TimelockError::TimestampOverflow)?;
+                Ok(check_time(median_time, unlock_time))
+            }
+        }
+    }
+}
+
+fn check_height(spend_height: BlockHeight, unlock_height: BlockHeight) -> TimelockState {
+    if spend_height >= unlock_height {
+        TimelockState::Unlocked
+    } else {
+        TimelockState::LockedUntilHeight(unlock_height)
+    }
+}
+
+fn check_time(median_time: BlockTimestamp, unlock_time: BlockTimestamp) -> TimelockState {
+    if median_time >= unlock_time {
+        TimelockState::Unlocked
+    } else {
+        TimelockState::LockedUntilTime(unlock_time)
+    }
+}
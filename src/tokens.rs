@@ -15,7 +15,7 @@
 
 use parity_scale_codec::{Decode, Encode};
 
-use crate::{Amount, Destination, PscVec, PublicKey};
+use crate::{Amount, ChainConfig, Destination, PscVec, PublicKey};
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, strum::EnumIter)]
 pub enum IsTokenFreezable {
@@ -68,6 +68,73 @@ pub struct TokenIssuanceV1 {
     pub is_freezable: IsTokenFreezable,
 }
 
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum TokenIssuanceError {
+    #[error("Invalid token ticker length {0}")]
+    InvalidTickerLength(usize),
+
+    #[error("Invalid character in the token ticker")]
+    InvalidTickerCharset,
+
+    #[error("Too many decimals: {0}")]
+    TooManyDecimals(u8),
+
+    #[error("Metadata URI is too long: {0} bytes")]
+    MetadataUriTooLong(usize),
+
+    #[error("Invalid character in the metadata URI")]
+    InvalidMetadataUriCharset,
+
+    #[error("Fixed total supply is zero")]
+    ZeroFixedSupply,
+}
+
+impl TokenIssuanceV1 {
+    /// Check the issuance against the same rules that Core applies to it.
+    pub fn validate(&self, chain_config: &ChainConfig) -> Result<(), TokenIssuanceError> {
+        let ticker_len = self.token_ticker.len();
+        if ticker_len == 0 || ticker_len > chain_config.token_max_ticker_len {
+            return Err(TokenIssuanceError::InvalidTickerLength(ticker_len));
+        }
+        if !is_valid_ticker(&self.token_ticker) {
+            return Err(TokenIssuanceError::InvalidTickerCharset);
+        }
+
+        if self.number_of_decimals > chain_config.token_max_dec_count {
+            return Err(TokenIssuanceError::TooManyDecimals(self.number_of_decimals));
+        }
+
+        let uri_len = self.metadata_uri.len();
+        if uri_len > chain_config.token_max_uri_len {
+            return Err(TokenIssuanceError::MetadataUriTooLong(uri_len));
+        }
+        if !is_valid_uri(&self.metadata_uri) {
+            return Err(TokenIssuanceError::InvalidMetadataUriCharset);
+        }
+
+        match self.total_supply {
+            TokenTotalSupply::Fixed(amount) if amount == Amount::ZERO => {
+                Err(TokenIssuanceError::ZeroFixedSupply)
+            }
+            TokenTotalSupply::Fixed(_)
+            | TokenTotalSupply::Lockable
+            | TokenTotalSupply::Unlimited => Ok(()),
+        }
+    }
+}
+
+/// Token tickers may only contain ASCII letters and digits.
+fn is_valid_ticker(ticker: &[u8]) -> bool {
+    ticker.iter().all(u8::is_ascii_alphanumeric)
+}
+
+/// URIs may only contain the characters allowed by RFC 3986 (unreserved, reserved and
+/// the percent sign).
+fn is_valid_uri(uri: &[u8]) -> bool {
+    uri.iter()
+        .all(|c| c.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=%".contains(c))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, strum::EnumDiscriminants)]
 #[strum_discriminants(name(NftIssuanceTag), derive(strum::EnumIter))]
 pub enum NftIssuance {
@@ -86,3 +153,94 @@ pub struct NftIssuanceV0 {
     pub media_uri: PscVec<u8>,
     pub media_hash: PscVec<u8>,
 }
+
+/// A field of `NftIssuanceV0`, used to specify which one is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NftIssuanceField {
+    Name,
+    Description,
+    Ticker,
+    IconUri,
+    AdditionalMetadataUri,
+    MediaUri,
+    MediaHash,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum NftIssuanceError {
+    #[error("Invalid NFT {field:?} length {len}")]
+    InvalidLength { field: NftIssuanceField, len: usize },
+
+    #[error("Invalid character in NFT {field:?}")]
+    InvalidCharset { field: NftIssuanceField },
+}
+
+impl NftIssuanceV0 {
+    /// Check the issuance against the same rules that Core applies to it.
+    ///
+    /// The URIs are optional, i.e. they may be empty; all other fields are mandatory.
+    /// The description may be any UTF-8 text.
+    pub fn validate(&self, chain_config: &ChainConfig) -> Result<(), NftIssuanceError> {
+        check_nft_field(
+            NftIssuanceField::Name,
+            &self.name,
+            1..=chain_config.token_max_name_len,
+            is_valid_nft_name,
+        )?;
+        check_nft_field(
+            NftIssuanceField::Description,
+            &self.description,
+            1..=chain_config.token_max_description_len,
+            |description| core::str::from_utf8(description).is_ok(),
+        )?;
+        check_nft_field(
+            NftIssuanceField::Ticker,
+            &self.ticker,
+            1..=chain_config.token_max_ticker_len,
+            is_valid_ticker,
+        )?;
+
+        for (field, uri) in [
+            (NftIssuanceField::IconUri, &self.icon_uri),
+            (
+                NftIssuanceField::AdditionalMetadataUri,
+                &self.additional_metadata_uri,
+            ),
+            (NftIssuanceField::MediaUri, &self.media_uri),
+        ] {
+            check_nft_field(field, uri, 0..=chain_config.token_max_uri_len, is_valid_uri)?;
+        }
+
+        check_nft_field(
+            NftIssuanceField::MediaHash,
+            &self.media_hash,
+            chain_config.token_min_hash_len..=chain_config.token_max_hash_len,
+            |_| true,
+        )
+    }
+}
+
+fn check_nft_field(
+    field: NftIssuanceField,
+    value: &[u8],
+    allowed_len: core::ops::RangeInclusive<usize>,
+    is_valid: impl Fn(&[u8]) -> bool,
+) -> Result<(), NftIssuanceError> {
+    if !allowed_len.contains(&value.len()) {
+        return Err(NftIssuanceError::InvalidLength {
+            field,
+            len: value.len(),
+        });
+    }
+
+    if !is_valid(value) {
+        return Err(NftIssuanceError::InvalidCharset { field });
+    }
+
+    Ok(())
+}
+
+/// NFT names may only contain ASCII letters and digits.
+fn is_valid_nft_name(name: &[u8]) -> bool {
+    name.iter().all(u8::is_ascii_alphanumeric)
+}
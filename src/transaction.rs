@@ -0,0 +1,132 @@
+// Copyright (c) 2024-2025 RBB S.r.l
+// opensource@mintlayer.org
+// SPDX-License-Identifier: MIT
+// Licensed under the MIT License;
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// https://github.com/mintlayer/mintlayer-core-primitives/blob/master/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::{Decode, Encode};
+
+use crate::{PscVec, TransactionId, TxInput, TxOutput, hash_encoded};
+
+/// A zero-sized type that is encoded as the single byte `V`; decoding any other byte fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct VersionTag<const V: u8>;
+
+impl<const V: u8> Encode for VersionTag<V> {
+    fn size_hint(&self) -> usize {
+        1
+    }
+
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        dest.push_byte(V);
+    }
+}
+
+impl<const V: u8> Decode for VersionTag<V> {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        if input.read_byte()? == V {
+            Ok(Self)
+        } else {
+            Err("Unexpected version tag".into())
+        }
+    }
+}
+
+/// The version byte of `TransactionV1`.
+pub const TX_V1_VERSION_BYTE: u8 = 1;
+
+/// A transaction.
+///
+/// Note: in Mintlayer Core the transaction is "directly encoded", i.e. there is no separate
+/// variant index in the encoded form; the role of the index is played by the version byte,
+/// which is a part of the versioned transaction itself.
+#[derive(Debug, Clone, PartialEq, Eq, strum::EnumDiscriminants)]
+#[strum_discriminants(name(TransactionTag), derive(strum::EnumIter))]
+pub enum Transaction {
+    V1(TransactionV1),
+}
+
+impl Transaction {
+    pub fn version_byte(&self) -> u8 {
+        match self {
+            Transaction::V1(_) => TX_V1_VERSION_BYTE,
+        }
+    }
+
+    pub fn flags(&self) -> u128 {
+        match self {
+            Transaction::V1(tx) => tx.flags,
+        }
+    }
+
+    pub fn inputs(&self) -> &[TxInput] {
+        match self {
+            Transaction::V1(tx) => &tx.inputs,
+        }
+    }
+
+    pub fn outputs(&self) -> &[TxOutput] {
+        match self {
+            Transaction::V1(tx) => &tx.outputs,
+        }
+    }
+
+    /// Calculate the id of the transaction, which is the hash of its encoded form.
+    pub fn get_id(&self) -> TransactionId {
+        TransactionId::new(hash_encoded(self))
+    }
+}
+
+impl Encode for Transaction {
+    fn size_hint(&self) -> usize {
+        match self {
+            Transaction::V1(tx) => tx.size_hint(),
+        }
+    }
+
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+        match self {
+            Transaction::V1(tx) => tx.encode_to(dest),
+        }
+    }
+}
+
+impl Decode for Transaction {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        // V1 is the only version at this moment; its own version tag will reject anything else.
+        Ok(Transaction::V1(TransactionV1::decode(input)?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TransactionV1 {
+    pub version: VersionTag<TX_V1_VERSION_BYTE>,
+    #[codec(compact)]
+    pub flags: u128,
+    pub inputs: PscVec<TxInput>,
+    pub outputs: PscVec<TxOutput>,
+}
+
+impl TransactionV1 {
+    pub fn new(flags: u128, inputs: PscVec<TxInput>, outputs: PscVec<TxOutput>) -> Self {
+        Self {
+            version: VersionTag,
+            flags,
+            inputs,
+            outputs,
+        }
+    }
+}